@@ -28,7 +28,10 @@ use crate::configs::config_core::{AirdropTokens, ConfigCore, ConfigCoreContent};
 use crate::configs::config_mining::{ConfigMining, ConfigMiningContent};
 use crate::configs::config_pools::{ConfigPools, ConfigPoolsContent};
 use crate::configs::config_ui::{ConfigUI, ConfigUIContent, DisplayMode};
-use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent, WalletId};
+use crate::configs::config_wallet::{
+    ConfigWallet, ConfigWalletContent, ExternalTariAddressBookRecord, WalletId,
+    EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK, INTERNAL_WALLETS_DATA_DIR_NAME,
+};
 use crate::configs::pools::{cpu_pools::CpuPool, gpu_pools::GpuPool};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events::ConnectionStatusPayload;
@@ -36,13 +39,34 @@ use crate::events_emitter::EventsEmitter;
 use crate::events_manager::EventsManager;
 use crate::gpu_miner::EngineType;
 use crate::gpu_miner_adapter::GpuNodeSource;
-use crate::internal_wallet::{mnemonic_to_tari_cipher_seed, InternalWallet, PaperWalletConfig};
-use crate::node::node_adapter::BaseNodeStatus;
+use crate::internal_wallet::{
+    mnemonic_to_tari_cipher_seed, InternalWallet, InternalWalletInfo, PaperWalletConfig,
+};
+use crate::node::block_explorer::{
+    mark_won_blocks, BlockPowAlgorithm, ExplorerBlock, ExplorerBlockDetails,
+    DEFAULT_RECENT_BLOCKS_COUNT, MAX_RECENT_BLOCKS_COUNT,
+};
+use crate::node::chain_tip_verifier::{
+    stop_mining_on_minority_chain, to_connection_address, ChainTipVerification,
+};
+use crate::node::database_relocation::NodeDatabaseRelocation;
+use crate::node::mempool::{MempoolStats, MempoolTransactionStatus};
+use crate::node::network_stats_history::{
+    NetworkStatsHistory, NetworkStatsSample, HASHRATE_AVERAGE_WINDOW,
+};
+use crate::node::node_adapter::{BaseNodeStatus, ConnectedPeerInfo};
+use crate::node::node_config_overrides::NodeConfigOverrides;
 use crate::node::node_manager::NodeType;
+use crate::node::orphan_chain_recovery::{self, OrphanChainRecoveryConfig};
+use crate::node::snapshot_bootstrap::SnapshotSource;
+use crate::node::utils::get_node_db_dir;
 use crate::p2pool::models::{Connections, P2poolStats};
 use crate::pin::PinManager;
 use crate::release_notes::ReleaseNotes;
 use crate::setup::setup_manager::{SetupManager, SetupPhase};
+use crate::solo_mining_estimator::{
+    estimate_solo_mining, SoloMiningEstimate, SoloMiningLuckTracker, ALGORITHM_BLOCK_TIME_SECS,
+};
 use crate::system_dependencies::system_dependencies_manager::SystemDependenciesManager;
 use crate::tapplets::interface::ActiveTapplet;
 use crate::tapplets::tapplet_server::start_tapplet;
@@ -50,8 +74,33 @@ use crate::tasks_tracker::TasksTrackers;
 use crate::tor_adapter::TorConfig;
 use crate::utils::address_utils::verify_send;
 use crate::utils::app_flow_utils::FrontendReadyChannel;
+use crate::wallet::auto_sweep::{merge_rules, AutoSweepLogEntry, AutoSweepRule};
+use crate::wallet::batch_payment::{
+    BatchPaymentRecipient, BatchPaymentStatus, BatchPaymentSummary,
+};
+use crate::wallet::coinbase_maturity::CoinbaseOutputMaturity;
+use crate::wallet::contact_book::{
+    self, find_duplicate, parse_contacts, plan_import, user_contacts, Contact,
+    ContactBookFileFormat, ContactImportSummary, ContactInput,
+};
+use crate::wallet::fee_estimation::FeeEstimate;
+use crate::wallet::offline_signing::{
+    find_pending_offline_transactions, read_tx_id, PendingOfflineTransaction,
+};
+use crate::wallet::payment_requests::{self, PaymentRequest};
+use crate::wallet::spend_wallet::SpendWallet;
+use crate::wallet::transaction_export::{
+    write_export, ExportTimeZone, TransactionExportFormat, TransactionExportRecord,
+};
+use crate::wallet::transaction_history::TransactionHistoryFilter;
+use crate::wallet::transaction_search::{TransactionSearch, TransactionSearchQuery};
+use crate::wallet::transaction_service::get_transactions_directory;
 use crate::wallet::wallet_manager::WalletManagerError;
+use crate::wallet::wallet_rescan::{is_rescan_in_progress, run_wallet_rescan, WalletRescanTarget};
 use crate::wallet::wallet_types::{TariAddressVariants, TransactionInfo};
+use crate::wallet::watch_only::{
+    read_watch_only_package, write_watch_only_package, WatchOnlyPackage,
+};
 use crate::{airdrop, PoolStatus, UniverseAppState};
 
 use base64::prelude::*;
@@ -61,6 +110,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::Debug;
 use std::fs::{read_dir, remove_dir_all, remove_file, File};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
 use std::thread::sleep;
@@ -68,13 +118,12 @@ use std::time::{Duration, Instant};
 use tari_common::configuration::Network;
 use tari_common_types::seeds::mnemonic::{Mnemonic, MnemonicLanguage};
 use tari_common_types::seeds::mnemonic_wordlists::MNEMONIC_ENGLISH_WORDS;
-use tari_common_types::tari_address::dual_address::DualAddress;
 use tari_common_types::tari_address::{TariAddress, TariAddressFeatures};
 use tari_transaction_components::tari_amount::{MicroMinotari, Minotari};
 use tari_utilities::encoding::MBase58;
 use tari_utilities::SafePassword;
 use tauri::ipc::InvokeError;
-use tauri::Manager;
+use tauri::{Manager, Url};
 use tauri_plugin_sentry::sentry;
 use urlencoding::encode;
 
@@ -114,6 +163,7 @@ pub struct CpuMinerStatus {
     pub estimated_earnings: u64,
     pub connection: CpuMinerConnectionStatus,
     pub pool_status: Option<PoolStatus>,
+    pub solo_mining_estimate: Option<SoloMiningEstimate>,
 }
 
 impl Default for CpuMinerStatus {
@@ -126,6 +176,7 @@ impl Default for CpuMinerStatus {
                 is_connected: false,
             },
             pool_status: None,
+            solo_mining_estimate: None,
         }
     }
 }
@@ -687,6 +738,289 @@ pub async fn get_transactions(
     Ok(transactions)
 }
 
+#[tauri::command]
+pub async fn get_transaction_history(
+    state: tauri::State<'_, UniverseAppState>,
+    filter: Option<TransactionHistoryFilter>,
+    offset: Option<u32>,
+    limit: Option<u32>,
+) -> Result<Vec<TransactionInfo>, String> {
+    let timer = Instant::now();
+    let transactions = state
+        .wallet_manager
+        .get_transaction_history(&filter.unwrap_or_default(), offset, limit)
+        .await
+        .unwrap_or_else(|e| {
+            if !matches!(e, WalletManagerError::WalletNotStarted) {
+                warn!(target: LOG_TARGET, "Error getting transaction history: {e}");
+            }
+            vec![]
+        });
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_transaction_history took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(transactions)
+}
+
+#[tauri::command]
+pub async fn search_transactions(
+    state: tauri::State<'_, UniverseAppState>,
+    query: TransactionSearchQuery,
+    offset: Option<u32>,
+    limit: Option<u32>,
+) -> Result<Vec<TransactionInfo>, String> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET, "[search_transactions] called with query: {query:?}");
+    let search = TransactionSearch::try_from(query).map_err(|e| e.to_string())?;
+    let transactions = state
+        .wallet_manager
+        .search_transactions(&search, offset, limit)
+        .await
+        .unwrap_or_else(|e| {
+            if !matches!(e, WalletManagerError::WalletNotStarted) {
+                warn!(target: LOG_TARGET, "Error searching transactions: {e}");
+            }
+            vec![]
+        });
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "search_transactions took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(transactions)
+}
+
+#[tauri::command]
+pub async fn get_coinbase_maturity_schedule(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<CoinbaseOutputMaturity>, String> {
+    let timer = Instant::now();
+    let schedule = state
+        .wallet_manager
+        .get_coinbase_maturity_schedule()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_coinbase_maturity_schedule took too long: {:?}", timer.elapsed());
+    }
+    Ok(schedule)
+}
+
+#[tauri::command]
+pub async fn export_transaction_history(
+    path: String,
+    format: TransactionExportFormat,
+    from_timestamp: Option<u64>,
+    to_timestamp: Option<u64>,
+    use_local_time_zone: Option<bool>,
+    utc_offset_minutes: Option<i32>,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<usize, InvokeError> {
+    info!(target: LOG_TARGET, "[export_transaction_history] called with path: {path:?}, format: {format:?}, from_timestamp: {from_timestamp:?}, to_timestamp: {to_timestamp:?}");
+    let path = PathBuf::from(path);
+    if !path.is_absolute() {
+        return Err(InvokeError::from(
+            "Export path must be an absolute path".to_string(),
+        ));
+    }
+    let time_zone = ExportTimeZone::parse(use_local_time_zone.unwrap_or(false), utc_offset_minutes)
+        .map_err(InvokeError::from_anyhow)?;
+
+    let filter = TransactionHistoryFilter {
+        from_timestamp,
+        to_timestamp,
+        ..Default::default()
+    };
+    let records: Vec<TransactionExportRecord> = state
+        .wallet_manager
+        .get_transaction_history(&filter, None, None)
+        .await
+        .map_err(|e| InvokeError::from(e.to_string()))?
+        .iter()
+        .map(|transaction| TransactionExportRecord::new(transaction, time_zone))
+        .collect();
+
+    write_export(&path, format, &records)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    info!(target: LOG_TARGET, "Exported {} transactions to {}", records.len(), path.display());
+
+    Ok(records.len())
+}
+
+#[tauri::command]
+pub async fn get_auto_sweep_rules() -> Result<Vec<AutoSweepRule>, String> {
+    Ok(ConfigWallet::content().await.auto_sweep_rules().clone())
+}
+
+#[tauri::command]
+pub async fn set_auto_sweep_rules(rules: Vec<AutoSweepRule>) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[set_auto_sweep_rules] called with {} rules", rules.len());
+    let pin_locked = PinManager::pin_locked().await;
+    let config = ConfigWallet::content().await;
+    let rules = merge_rules(
+        config.auto_sweep_rules(),
+        rules,
+        config.external_tari_addresses_book(),
+        pin_locked,
+    )?;
+    ConfigWallet::update_field(ConfigWalletContent::set_auto_sweep_rules, rules)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_auto_sweep_log() -> Result<Vec<AutoSweepLogEntry>, String> {
+    Ok(ConfigWallet::content().await.auto_sweep_log().clone())
+}
+
+#[tauri::command]
+pub async fn get_contacts() -> Result<Vec<Contact>, String> {
+    let book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    Ok(user_contacts(&book)
+        .into_iter()
+        .map(Contact::from)
+        .collect())
+}
+
+#[tauri::command]
+pub async fn save_contact(
+    contact: ContactInput,
+    previous_name: Option<String>,
+) -> Result<Contact, String> {
+    info!(target: LOG_TARGET, "[save_contact] called with name: {:?}, previous_name: {previous_name:?}", contact.name);
+    let book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    let existing_name = previous_name.as_deref().unwrap_or(&contact.name);
+    let last_used_at = book
+        .get(existing_name)
+        .and_then(|record| record.last_used_at);
+    let record = contact.into_record(last_used_at)?;
+
+    if record.name == EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK {
+        return Err(format!("Contact name {} is reserved", record.name));
+    }
+    if previous_name.as_deref() != Some(record.name.as_str()) && book.contains_key(&record.name) {
+        return Err(format!("A contact named {} already exists", record.name));
+    }
+    if let Some(duplicate) = find_duplicate(&book, &record.address, Some(existing_name)) {
+        return Err(format!(
+            "This address is already saved as {}",
+            duplicate.name
+        ));
+    }
+
+    let contact = Contact::from(&record);
+    ConfigWallet::update_field(ConfigWalletContent::save_contact, (previous_name, record))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(contact)
+}
+
+#[tauri::command]
+pub async fn remove_contact(name: String) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[remove_contact] called with name: {name:?}");
+    ConfigWallet::update_field(ConfigWalletContent::remove_contact, name)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn export_contacts(
+    path: String,
+    format: ContactBookFileFormat,
+) -> Result<usize, InvokeError> {
+    info!(target: LOG_TARGET, "[export_contacts] called with path: {path:?}, format: {format:?}");
+    let path = PathBuf::from(path);
+    if !path.is_absolute() {
+        return Err(InvokeError::from(
+            "Export path must be an absolute path".to_string(),
+        ));
+    }
+    let book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    let records: Vec<ExternalTariAddressBookRecord> =
+        user_contacts(&book).into_iter().cloned().collect();
+
+    let contents =
+        contact_book::export_contacts(&records, format).map_err(InvokeError::from_anyhow)?;
+    tokio::fs::write(&path, contents)
+        .await
+        .map_err(|e| InvokeError::from_anyhow(e.into()))?;
+    info!(target: LOG_TARGET, "Exported {} contacts to {}", records.len(), path.display());
+
+    Ok(records.len())
+}
+
+#[tauri::command]
+pub async fn import_contacts(
+    path: String,
+    format: ContactBookFileFormat,
+) -> Result<ContactImportSummary, InvokeError> {
+    info!(target: LOG_TARGET, "[import_contacts] called with path: {path:?}, format: {format:?}");
+    let contents = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| InvokeError::from_anyhow(e.into()))?;
+    let inputs = parse_contacts(&contents, format).map_err(InvokeError::from_anyhow)?;
+
+    let book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    let (records, summary) = plan_import(&book, inputs);
+    for record in records {
+        ConfigWallet::update_field(ConfigWalletContent::save_contact, (None, record))
+            .await
+            .map_err(InvokeError::from_anyhow)?;
+    }
+    info!(target: LOG_TARGET, "Imported {} contacts, {} duplicates, {} invalid", summary.imported, summary.duplicates.len(), summary.invalid.len());
+
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn rescan_wallet(
+    target: WalletRescanTarget,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<u16, String> {
+    info!(target: LOG_TARGET, "[rescan_wallet] called with target: {target:?}");
+    if !InternalWallet::is_internal().await {
+        return Err("Rescanning requires an internal wallet".to_string());
+    }
+    if is_rescan_in_progress() {
+        return Err("Wallet rescan already in progress".to_string());
+    }
+
+    let birthday = match target {
+        WalletRescanTarget::Birthday { birthday } => birthday,
+        WalletRescanTarget::BlockHeight { height } => state
+            .wallet_manager
+            .birthday_for_height(height)
+            .await
+            .map_err(|e| e.to_string())?,
+    };
+
+    TasksTrackers::current()
+        .common
+        .get_task_tracker()
+        .await
+        .spawn(run_wallet_rescan(app_handle, birthday));
+    Ok(birthday)
+}
+
 #[tauri::command]
 pub async fn forgot_pin(
     seed_words: Vec<String>,
@@ -765,6 +1099,109 @@ pub async fn import_seed_words(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn list_internal_wallets() -> Result<Vec<InternalWalletInfo>, InvokeError> {
+    Ok(InternalWallet::list_tari_wallets().await)
+}
+
+#[tauri::command]
+pub async fn create_internal_wallet(
+    name: String,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET, "[create_internal_wallet] called with name: {name}");
+    if name.trim().is_empty() {
+        return Err(InvokeError::from("Wallet name can't be empty".to_string()));
+    }
+
+    SetupManager::get_instance()
+        .shutdown_phases(vec![SetupPhase::Wallet])
+        .await;
+    let result = InternalWallet::create_tari_wallet(&app_handle, name.trim().to_string()).await;
+    state.wallet_manager.reset_wallet_caches().await;
+    SetupManager::get_instance()
+        .resume_phases(vec![SetupPhase::Wallet])
+        .await;
+    let tari_wallet_details = result.map_err(InvokeError::from_anyhow)?;
+    reset_exchange_id_for_internal_wallet()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "create_internal_wallet took too long: {:?}", timer.elapsed());
+    }
+    Ok(tari_wallet_details.id.as_str().to_string())
+}
+
+#[tauri::command]
+pub async fn rename_internal_wallet(wallet_id: String, name: String) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[rename_internal_wallet] called with wallet_id: {wallet_id}, name: {name}");
+    if name.trim().is_empty() {
+        return Err(InvokeError::from("Wallet name can't be empty".to_string()));
+    }
+    ConfigWallet::update_field(
+        ConfigWalletContent::rename_internal_wallet,
+        (WalletId::new(wallet_id), name.trim().to_string()),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn delete_internal_wallet(
+    wallet_id: String,
+    confirmed: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[delete_internal_wallet] called with wallet_id: {wallet_id}, confirmed: {confirmed}");
+    InternalWallet::delete_tari_wallet(&app_handle, WalletId::new(wallet_id), confirmed)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn switch_internal_wallet(
+    wallet_id: String,
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET, "[switch_internal_wallet] called with wallet_id: {wallet_id}");
+
+    // Only the wallet phase is restarted, miners pick up the new address on their next start
+    SetupManager::get_instance()
+        .shutdown_phases(vec![SetupPhase::Wallet])
+        .await;
+    let result = InternalWallet::switch_tari_wallet(&app_handle, WalletId::new(wallet_id)).await;
+    state.wallet_manager.reset_wallet_caches().await;
+    SetupManager::get_instance()
+        .resume_phases(vec![SetupPhase::Wallet])
+        .await;
+    result.map_err(InvokeError::from_anyhow)?;
+    reset_exchange_id_for_internal_wallet()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "switch_internal_wallet took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+async fn reset_exchange_id_for_internal_wallet() -> Result<(), anyhow::Error> {
+    ConfigCore::update_field(
+        ConfigCoreContent::set_exchange_id,
+        DEFAULT_EXCHANGE_ID.to_string(),
+    )
+    .await?;
+    EventsEmitter::emit_exchange_id_changed(DEFAULT_EXCHANGE_ID.to_string()).await;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn revert_to_internal_wallet(
     _window: tauri::Window,
@@ -899,6 +1336,7 @@ pub async fn reset_settings(
             .map_err(|e| e.to_string())?;
     } else {
         folder_block_list.push("wallet");
+        folder_block_list.push(INTERNAL_WALLETS_DATA_DIR_NAME);
         files_block_list.push("credentials_backup.bin");
     }
     // handle App Config reset individually
@@ -1473,6 +1911,15 @@ pub async fn start_cpu_mining(
 
     if cpu_mining_enabled && !cpu_miner_running {
         let cpu_miner_config = state.cpu_miner_config.read().await;
+        if matches!(
+            cpu_miner_config.node_connection,
+            CpuMinerConnection::BuiltInProxy
+        ) && state.node_manager.is_on_minority_chain()
+        {
+            let err_msg = "Could not start CPU mining: node is on a minority chain".to_string();
+            error!(target: LOG_TARGET, "{err_msg}");
+            return Err(err_msg);
+        }
         let mmproxy_manager = &state.mm_proxy_manager;
         let mut cpu_miner = state.cpu_miner.write().await;
         let res = cpu_miner
@@ -1582,6 +2029,12 @@ pub async fn start_gpu_mining(
             return Err(e.to_string());
         }
     } else {
+        if state.node_manager.is_on_minority_chain() {
+            let err_msg = "Could not start GPU mining: node is on a minority chain".to_string();
+            error!(target: LOG_TARGET, "{err_msg}");
+            return Err(err_msg);
+        }
+
         let grpc_address = state
             .node_manager
             .get_grpc_address()
@@ -1891,14 +2344,22 @@ pub async fn send_one_sided_to_stealth_address(
     amount: String,
     destination: String,
     payment_id: Option<String>,
+    fee_per_gram: Option<u64>,
 ) -> Result<(), String> {
     let timer = Instant::now();
-    info!(target: LOG_TARGET, "[send_one_sided_to_stealth_address] called with args: (amount: {amount:?}, destination: {destination:?}, payment_id: {payment_id:?})");
+    info!(target: LOG_TARGET, "[send_one_sided_to_stealth_address] called with args: (amount: {amount:?}, destination: {destination:?}, payment_id: {payment_id:?}, fee_per_gram: {fee_per_gram:?})");
     state
         .wallet_manager
-        .send_one_sided_to_stealth_address(amount, destination, payment_id, &app_handle)
+        .send_one_sided_to_stealth_address(
+            amount,
+            destination.clone(),
+            payment_id,
+            fee_per_gram,
+            &app_handle,
+        )
         .await
         .map_err(|e| e.to_string())?;
+    contact_book::mark_contact_used(&destination).await;
 
     let balance = state.wallet_manager.get_balance().await;
     if let Ok(balance) = balance {
@@ -1912,45 +2373,292 @@ pub async fn send_one_sided_to_stealth_address(
 }
 
 #[tauri::command]
-pub fn verify_address_for_send(
-    address: String,
-    sending_method: Option<TariAddressFeatures>,
-) -> Result<(), String> {
-    let sending_method = sending_method.unwrap_or(TariAddressFeatures::ONE_SIDED);
-
-    verify_send(address, sending_method)
-}
-
-#[tauri::command]
-pub fn validate_minotari_amount(
-    amount: String,
+pub async fn send_batch_payment(
     state: tauri::State<'_, UniverseAppState>,
-) -> Result<(), InvokeError> {
-    let t_amount = Minotari::from_str(&amount).map_err(|e| e.to_string())?;
-    let m_amount = MicroMinotari::from(t_amount);
+    app_handle: tauri::AppHandle,
+    recipients: Vec<BatchPaymentRecipient>,
+    fee_per_gram: Option<u64>,
+) -> Result<BatchPaymentSummary, String> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET, "[send_batch_payment] called with {} recipients, fee_per_gram: {fee_per_gram:?}", recipients.len());
+    let summary = state
+        .wallet_manager
+        .send_batch_payment(recipients, fee_per_gram, &app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+    for result in &summary.results {
+        if matches!(result.status, BatchPaymentStatus::Sent) {
+            contact_book::mark_contact_used(&result.address).await;
+        }
+    }
 
-    let balance = state
-        .wallet_state_watch_rx
-        .borrow()
-        .clone()
-        .and_then(|state| state.balance);
+    let balance = state.wallet_manager.get_balance().await;
+    if let Ok(balance) = balance {
+        EventsEmitter::emit_wallet_balance_update(balance).await;
+    }
 
-    let available_balance = balance.expect("Could not get balance").available_balance;
-    match m_amount.cmp(&available_balance) {
-        std::cmp::Ordering::Less => Ok(()),
-        _ => Err(InvokeError::from("Insufficient balance".to_string())),
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "send_batch_payment took too long: {:?}", timer.elapsed());
     }
+    Ok(summary)
 }
 
 #[tauri::command]
-pub async fn trigger_phases_restart() -> Result<(), InvokeError> {
-    SetupManager::get_instance()
-        .restart_phases_from_queue()
-        .await;
-
-    Ok(())
-}
-
+pub async fn prepare_offline_transaction(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+    amount: String,
+    destination: String,
+    payment_id: Option<String>,
+    fee_per_gram: Option<u64>,
+    path: String,
+) -> Result<String, String> {
+    info!(target: LOG_TARGET, "[prepare_offline_transaction] called with args: (amount: {amount:?}, destination: {destination:?}, payment_id: {payment_id:?}, fee_per_gram: {fee_per_gram:?}, path: {path:?})");
+    let path = PathBuf::from(path);
+    if !path.is_absolute() {
+        return Err("Export path must be an absolute path".to_string());
+    }
+    state
+        .wallet_manager
+        .prepare_offline_transaction(
+            amount,
+            destination,
+            payment_id,
+            fee_per_gram,
+            &path,
+            &app_handle,
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn sign_offline_transaction(
+    app_handle: tauri::AppHandle,
+    input_path: String,
+    output_path: String,
+) -> Result<String, String> {
+    info!(target: LOG_TARGET, "[sign_offline_transaction] called with input_path: {input_path:?}, output_path: {output_path:?}");
+    let input_path = PathBuf::from(input_path);
+    let output_path = PathBuf::from(output_path);
+    if !input_path.is_absolute() || !output_path.is_absolute() {
+        return Err("Transaction paths must be absolute paths".to_string());
+    }
+    let unsigned_tx_json = tokio::fs::read_to_string(&input_path)
+        .await
+        .map_err(|e| e.to_string())?;
+    let tx_id = read_tx_id(&unsigned_tx_json).map_err(|e| e.to_string())?;
+
+    SpendWallet::new()
+        .sign_offline_transaction(input_path, output_path, &app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+    info!(target: LOG_TARGET, "Signed offline transaction {tx_id}");
+
+    Ok(tx_id)
+}
+
+#[tauri::command]
+pub async fn broadcast_offline_signed_transaction(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+    path: String,
+) -> Result<String, String> {
+    info!(target: LOG_TARGET, "[broadcast_offline_signed_transaction] called with path: {path:?}");
+    let tx_id = state
+        .wallet_manager
+        .broadcast_offline_signed_transaction(&PathBuf::from(path), &app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let balance = state.wallet_manager.get_balance().await;
+    if let Ok(balance) = balance {
+        EventsEmitter::emit_wallet_balance_update(balance).await;
+    }
+    Ok(tx_id)
+}
+
+#[tauri::command]
+pub async fn cancel_offline_transaction(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+    tx_id: String,
+) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[cancel_offline_transaction] called with tx_id: {tx_id:?}");
+    state
+        .wallet_manager
+        .cancel_offline_transaction(tx_id, &app_handle)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn cancel_transaction(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+    tx_id: String,
+) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[cancel_transaction] called with tx_id: {tx_id:?}");
+    state
+        .wallet_manager
+        .cancel_transaction(tx_id, &app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Ok(balance) = state.wallet_manager.get_balance().await {
+        EventsEmitter::emit_wallet_balance_update(balance).await;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn rebroadcast_transaction(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+    tx_id: String,
+) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[rebroadcast_transaction] called with tx_id: {tx_id:?}");
+    state
+        .wallet_manager
+        .rebroadcast_transaction(tx_id, &app_handle)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_pending_offline_transactions(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<PendingOfflineTransaction>, String> {
+    let transactions_dir = get_transactions_directory(&app_handle).map_err(|e| e.to_string())?;
+    find_pending_offline_transactions(&transactions_dir).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_signer_only_mode(enabled: bool) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[set_signer_only_mode] called with enabled: {enabled}");
+    ConfigWallet::update_field(ConfigWalletContent::set_signer_only_mode, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_ask_for_restart().await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn export_watch_only_package(
+    app_handle: tauri::AppHandle,
+    path: String,
+    passphrase: String,
+) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[export_watch_only_package] called with path: {path:?}");
+    let path = PathBuf::from(path);
+    if !path.is_absolute() {
+        return Err("Export path must be an absolute path".to_string());
+    }
+    if passphrase.is_empty() {
+        return Err("Watch-only packages must be protected with a passphrase".to_string());
+    }
+    if InternalWallet::is_watch_only().await {
+        return Err("A watch-only wallet can't be exported again".to_string());
+    }
+    let tari_wallet_details = match InternalWallet::tari_wallet_details().await {
+        Some(details) if InternalWallet::is_internal().await => details,
+        _ => return Err("Watch-only packages need an internal wallet".to_string()),
+    };
+    // The view key reveals the whole income history, so exporting it always asks for the PIN
+    if !PinManager::pin_locked().await {
+        return Err("Set up a PIN before exporting a watch-only package".to_string());
+    }
+    PinManager::get_validated_pin(&app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let package = WatchOnlyPackage::from_wallet_details(&tari_wallet_details);
+    write_watch_only_package(&package, &path, &SafePassword::from(passphrase))
+        .map_err(|e| e.to_string())?;
+    info!(target: LOG_TARGET, "Exported watch-only package to {}", path.display());
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn enable_watch_only_mode(path: String, passphrase: String) -> Result<String, String> {
+    info!(target: LOG_TARGET, "[enable_watch_only_mode] called with path: {path:?}");
+    let package = read_watch_only_package(&PathBuf::from(path), &SafePassword::from(passphrase))
+        .map_err(|e| e.to_string())?;
+    let tari_address = package.tari_address.clone();
+    ConfigWallet::update_field(ConfigWalletContent::set_watch_only_package, Some(package))
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_ask_for_restart().await;
+    Ok(tari_address)
+}
+
+#[tauri::command]
+pub async fn disable_watch_only_mode() -> Result<(), String> {
+    info!(target: LOG_TARGET, "[disable_watch_only_mode] called");
+    ConfigWallet::update_field(ConfigWalletContent::set_watch_only_package, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_ask_for_restart().await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn estimate_transaction_fee(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<FeeEstimate, String> {
+    let timer = Instant::now();
+    let estimate = state
+        .wallet_manager
+        .estimate_transaction_fee()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "estimate_transaction_fee took too long: {:?}", timer.elapsed());
+    }
+    Ok(estimate)
+}
+
+#[tauri::command]
+pub fn verify_address_for_send(
+    address: String,
+    sending_method: Option<TariAddressFeatures>,
+) -> Result<(), String> {
+    let sending_method = sending_method.unwrap_or(TariAddressFeatures::ONE_SIDED);
+
+    verify_send(address, sending_method)
+}
+
+#[tauri::command]
+pub fn validate_minotari_amount(
+    amount: String,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    let t_amount = Minotari::from_str(&amount).map_err(|e| e.to_string())?;
+    let m_amount = MicroMinotari::from(t_amount);
+
+    let balance = state
+        .wallet_state_watch_rx
+        .borrow()
+        .clone()
+        .and_then(|state| state.balance);
+
+    let available_balance = balance.expect("Could not get balance").available_balance;
+    match m_amount.cmp(&available_balance) {
+        std::cmp::Ordering::Less => Ok(()),
+        _ => Err(InvokeError::from("Insufficient balance".to_string())),
+    }
+}
+
+#[tauri::command]
+pub async fn trigger_phases_restart() -> Result<(), InvokeError> {
+    SetupManager::get_instance()
+        .restart_phases_from_queue()
+        .await;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn restart_phases(phases: Vec<SetupPhase>) -> Result<(), InvokeError> {
     SetupManager::get_instance().restart_phases(phases).await;
@@ -2007,6 +2715,157 @@ pub async fn set_node_type(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn bootstrap_node_from_snapshot(
+    manifest_url: String,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[bootstrap_node_from_snapshot] called with manifest_url: {manifest_url:?}");
+    queue_node_snapshot(&state, SnapshotSource::Download { manifest_url }).await
+}
+
+#[tauri::command]
+pub async fn import_node_snapshot(
+    archive_path: String,
+    manifest_path: Option<String>,
+    allow_unverified: bool,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[import_node_snapshot] called with archive_path: {archive_path:?}, manifest_path: {manifest_path:?}, allow_unverified: {allow_unverified}");
+    let archive_path = PathBuf::from(archive_path);
+    if !archive_path.exists() {
+        return Err(InvokeError::from(format!(
+            "Snapshot archive does not exist: {}",
+            archive_path.display()
+        )));
+    }
+
+    queue_node_snapshot(
+        &state,
+        SnapshotSource::Import {
+            archive_path,
+            manifest_path: manifest_path.map(PathBuf::from),
+            allow_unverified,
+        },
+    )
+    .await
+}
+
+async fn queue_node_snapshot(
+    state: &tauri::State<'_, UniverseAppState>,
+    source: SnapshotSource,
+) -> Result<(), InvokeError> {
+    if !state.node_manager.is_local().await {
+        return Err(InvokeError::from(
+            "Snapshots can only be applied to the local node".to_string(),
+        ));
+    }
+
+    state.node_manager.set_pending_snapshot(source).await;
+    SetupManager::get_instance()
+        .restart_phases(vec![
+            SetupPhase::Node,
+            SetupPhase::Wallet,
+            SetupPhase::CpuMining,
+        ])
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn move_node_database(
+    node_data_dir: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[move_node_database] called with node_data_dir: {node_data_dir:?}");
+    let new_node_data_dir = node_data_dir.map(PathBuf::from);
+    if new_node_data_dir
+        .as_ref()
+        .is_some_and(|dir| !dir.is_absolute())
+    {
+        return Err(InvokeError::from(
+            "Node data directory must be an absolute path".to_string(),
+        ));
+    }
+
+    let current_node_data_dir = ConfigCore::content().await.node_data_dir().clone();
+    if current_node_data_dir == new_node_data_dir {
+        return Ok(());
+    }
+
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|_| "Could not find node data dir".to_string())?;
+    let relocation = NodeDatabaseRelocation::prepare(
+        get_node_db_dir(&base_path, current_node_data_dir.as_deref()),
+        get_node_db_dir(&base_path, new_node_data_dir.as_deref()),
+    )
+    .map_err(InvokeError::from_anyhow)?;
+
+    let phases = vec![
+        SetupPhase::Node,
+        SetupPhase::Wallet,
+        SetupPhase::CpuMining,
+        SetupPhase::GpuMining,
+    ];
+    SetupManager::get_instance()
+        .shutdown_phases(phases.clone())
+        .await;
+
+    let result = match relocation.run().await {
+        Ok(()) => {
+            ConfigCore::update_field(ConfigCoreContent::set_node_data_dir, new_node_data_dir).await
+        }
+        Err(e) => Err(e),
+    };
+    SetupManager::get_instance().resume_phases(phases).await;
+
+    result.map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn set_node_config_overrides(
+    config_overrides: NodeConfigOverrides,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<String>, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET, "[set_node_config_overrides] called with config_overrides: {config_overrides:?}");
+    let (_, warnings) = config_overrides.resolve();
+    for warning in &warnings {
+        warn!(target: LOG_TARGET, "[set_node_config_overrides] {warning}");
+    }
+
+    // A remote node ignores the overrides, they are applied once the local node is started
+    if state.node_manager.is_local().await {
+        // The overrides only change how the node is started, mining keeps running through the
+        // restart like it does when the Tor config changes
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_node_config_overrides,
+            config_overrides,
+            vec![SetupPhase::Node, SetupPhase::Wallet],
+        )
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+        SetupManager::get_instance()
+            .restart_phases_from_queue()
+            .await;
+    } else {
+        ConfigCore::update_field(
+            ConfigCoreContent::set_node_config_overrides,
+            config_overrides,
+        )
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_node_config_overrides took too long: {:?}", timer.elapsed());
+    }
+    Ok(warnings)
+}
+
 #[tauri::command]
 pub async fn change_cpu_pool(cpu_pool: String) -> Result<(), InvokeError> {
     let timer = Instant::now();
@@ -2182,11 +3041,7 @@ pub async fn get_bridge_envs() -> Result<(String, String), String> {
 pub async fn parse_tari_address(address: String) -> Result<TariAddressVariants, String> {
     let tari_address = TariAddress::from_str(&address).map_err(|e| e.to_string())?;
 
-    Ok(TariAddressVariants {
-        emoji_string: tari_address.to_emoji_string(),
-        base58: tari_address.to_base58(),
-        hex: tari_address.to_hex(),
-    })
+    Ok(TariAddressVariants::from(&tari_address))
 }
 
 #[tauri::command]
@@ -2200,6 +3055,219 @@ pub async fn list_connected_peers(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn get_connected_peers_info(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<ConnectedPeerInfo>, String> {
+    state
+        .node_manager
+        .get_connected_peers_info()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_orphan_chain_recovery_config(
+    config: OrphanChainRecoveryConfig,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[set_orphan_chain_recovery_config] called with config: {config:?}");
+    if let Some(manifest_url) = &config.snapshot_manifest_url {
+        if Url::parse(manifest_url).is_err() {
+            return Err(InvokeError::from(format!(
+                "Invalid snapshot manifest url: {manifest_url}"
+            )));
+        }
+    }
+
+    // Read by the periodic orphan chain check, no restart needed
+    ConfigCore::update_field(ConfigCoreContent::set_orphan_chain_recovery, config)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn confirm_orphan_chain_resync(
+    confirmed: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[confirm_orphan_chain_resync] called with confirmed: {confirmed}");
+    orphan_chain_recovery::confirm_orphan_chain_resync(app_handle, confirmed)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn get_recent_blocks(
+    count: Option<u64>,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<ExplorerBlock>, String> {
+    let timer = Instant::now();
+    let count = count
+        .unwrap_or(DEFAULT_RECENT_BLOCKS_COUNT)
+        .clamp(1, MAX_RECENT_BLOCKS_COUNT);
+    let mut blocks = state
+        .node_manager
+        .get_recent_blocks(count)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match state.wallet_manager.get_won_block_heights().await {
+        Ok(won_heights) => mark_won_blocks(blocks.iter_mut(), &won_heights),
+        Err(e) => {
+            warn!(target: LOG_TARGET, "[get_recent_blocks] Could not match coinbase transactions: {e}")
+        }
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_recent_blocks took too long: {:?}", timer.elapsed());
+    }
+    Ok(blocks)
+}
+
+#[tauri::command]
+pub async fn get_block_details(
+    height: u64,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<ExplorerBlockDetails, String> {
+    let mut details = state
+        .node_manager
+        .get_block_details(height)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match state.wallet_manager.is_block_won(height).await {
+        Ok(is_won) => details.block.is_won_by_wallet = is_won,
+        Err(e) => {
+            warn!(target: LOG_TARGET, "[get_block_details] Could not match coinbase transaction: {e}")
+        }
+    }
+
+    Ok(details)
+}
+
+#[tauri::command]
+pub async fn get_network_stats_history(
+    from_height: Option<u64>,
+) -> Result<Vec<NetworkStatsSample>, String> {
+    Ok(NetworkStatsHistory::current()
+        .read()
+        .await
+        .samples_from(from_height))
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SoloMiningEstimates {
+    pub cpu: SoloMiningEstimate,
+    pub gpu: SoloMiningEstimate,
+}
+
+#[tauri::command]
+pub async fn get_solo_mining_estimate(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<SoloMiningEstimates, String> {
+    let node_status = *state.node_status_watch_rx.borrow();
+    let cpu_hash_rate = state.cpu_miner_status_watch_rx.borrow().hash_rate;
+    let gpu_hash_rate = state.gpu_latest_status.borrow().hash_rate;
+
+    let network_stats_history = NetworkStatsHistory::current().read().await;
+    let cpu_network_hashrate = network_stats_history
+        .average_hashrate(BlockPowAlgorithm::RandomXM, HASHRATE_AVERAGE_WINDOW)
+        .unwrap_or(node_status.monero_randomx_network_hashrate);
+    let gpu_network_hashrate = network_stats_history
+        .average_hashrate(BlockPowAlgorithm::Sha3x, HASHRATE_AVERAGE_WINDOW)
+        .unwrap_or(node_status.sha_network_hashrate);
+    drop(network_stats_history);
+
+    let luck_tracker = SoloMiningLuckTracker::current().read().await;
+    Ok(SoloMiningEstimates {
+        cpu: SoloMiningEstimate {
+            luck: luck_tracker.luck(BlockPowAlgorithm::RandomXM),
+            ..estimate_solo_mining(
+                cpu_hash_rate,
+                cpu_network_hashrate,
+                ALGORITHM_BLOCK_TIME_SECS,
+            )
+        },
+        gpu: SoloMiningEstimate {
+            luck: luck_tracker.luck(BlockPowAlgorithm::Sha3x),
+            ..estimate_solo_mining(
+                gpu_hash_rate,
+                gpu_network_hashrate,
+                ALGORITHM_BLOCK_TIME_SECS,
+            )
+        },
+    })
+}
+
+#[tauri::command]
+pub async fn get_mempool_stats(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<MempoolStats, String> {
+    state
+        .node_manager
+        .get_mempool_stats()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_mempool_transaction_status(
+    excess_sig: Vec<u8>,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<MempoolTransactionStatus, String> {
+    if excess_sig.is_empty() {
+        return Err("Transaction has no excess signature yet".to_string());
+    }
+    state
+        .node_manager
+        .get_mempool_transaction_status(&excess_sig)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn verify_chain_tip(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<Option<ChainTipVerification>, String> {
+    let verification = state
+        .node_manager
+        .verify_chain_tip()
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(verification) = verification.clone() {
+        let is_minority_chain = verification.is_minority_chain;
+        EventsEmitter::emit_chain_tip_verification(verification).await;
+        if is_minority_chain {
+            stop_mining_on_minority_chain(&app_handle).await;
+        }
+    }
+
+    Ok(verification)
+}
+
+#[tauri::command]
+pub async fn set_chain_tip_verification_nodes(nodes: Vec<String>) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[set_chain_tip_verification_nodes] called with nodes: {nodes:?}");
+    let nodes: Vec<String> = nodes
+        .iter()
+        .map(|node| node.trim().to_string())
+        .filter(|node| !node.is_empty())
+        .collect();
+    for node in &nodes {
+        if Url::parse(&to_connection_address(node)).is_err() {
+            return Err(InvokeError::from(format!(
+                "Invalid chain tip verification node address: {node}"
+            )));
+        }
+    }
+
+    // Read by the periodic chain tip check, no restart needed
+    ConfigCore::update_field(ConfigCoreContent::set_chain_tip_verification_nodes, nodes)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
 #[tauri::command]
 pub async fn refresh_wallet_history(
     state: tauri::State<'_, UniverseAppState>,
@@ -2222,7 +3290,7 @@ pub async fn refresh_wallet_history(
     // Trigger it manually to immediately update the UI
     let node_status_watch_rx = state.node_status_watch_rx.clone();
     let node_status = *node_status_watch_rx.borrow();
-    EventsEmitter::emit_init_wallet_scanning_progress(0, node_status.block_height, 0.0).await;
+    EventsEmitter::emit_init_wallet_scanning_progress(0, node_status.block_height, 0.0, None).await;
 
     SetupManager::get_instance()
         .resume_phases(vec![SetupPhase::Wallet])
@@ -2240,23 +3308,57 @@ pub async fn encode_payment_id_to_address(
     tari_address: String,
 ) -> Result<String, String> {
     info!(target: LOG_TARGET, "encode_payment_id_to_address called with payment_id: {payment_id:?}, tari_address: {tari_address:?}");
-    let mut address_with_memo_field =
-        DualAddress::from_base58(tari_address.as_str()).map_err(|e| {
-            error!(target: LOG_TARGET, "Failed to parse Tari address: {e}");
-            e.to_string()
-        })?;
-    address_with_memo_field
-        .add_memo_field_payment_id(payment_id.as_bytes().to_vec())
+    let address_base58 = payment_requests::encode_payment_id_to_address(&tari_address, &payment_id)
         .map_err(|e| {
-            error!(target: LOG_TARGET, "Failed to add payment ID to Tari address: {e}");
+            error!(target: LOG_TARGET, "{e}");
             e.to_string()
         })?;
-    let address_base58 = address_with_memo_field.to_base58();
     info!(target: LOG_TARGET, "Encoded Tari address with payment ID: {address_base58:?}");
 
     Ok(address_base58)
 }
 
+#[tauri::command]
+pub async fn create_payment_request(
+    amount: String,
+    memo: Option<String>,
+    expires_in_secs: Option<u64>,
+) -> Result<PaymentRequest, String> {
+    info!(target: LOG_TARGET, "[create_payment_request] called with amount: {amount:?}, memo: {memo:?}, expires_in_secs: {expires_in_secs:?}");
+    if !InternalWallet::is_internal().await {
+        return Err("Payment requests need an internal wallet".to_string());
+    }
+    let amount = MicroMinotari::from(Minotari::from_str(&amount).map_err(|e| e.to_string())?);
+    if amount == MicroMinotari(0) {
+        return Err("Amount must be greater than zero".to_string());
+    }
+
+    let request = PaymentRequest::new(
+        amount,
+        memo.unwrap_or_default(),
+        &InternalWallet::tari_address().await,
+        expires_in_secs,
+    )
+    .map_err(|e| e.to_string())?;
+    ConfigWallet::update_field(ConfigWalletContent::add_payment_request, request.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(request)
+}
+
+#[tauri::command]
+pub async fn get_payment_requests() -> Result<Vec<PaymentRequest>, String> {
+    Ok(ConfigWallet::content().await.payment_requests().clone())
+}
+
+#[tauri::command]
+pub async fn delete_payment_request(payment_id: String) -> Result<(), String> {
+    info!(target: LOG_TARGET, "[delete_payment_request] called with payment_id: {payment_id}");
+    ConfigWallet::update_field(ConfigWalletContent::remove_payment_request, payment_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn save_wxtm_address(address: String, exchange_id: String) -> Result<(), String> {
     ConfigWallet::update_field(
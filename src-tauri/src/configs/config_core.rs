@@ -23,14 +23,16 @@
 use getset::{Getters, Setters};
 use semver::Version;
 use serde::{Deserialize, Serialize};
-use std::{sync::LazyLock, time::SystemTime};
+use std::{path::PathBuf, sync::LazyLock, time::SystemTime};
 use tari_common::configuration::Network;
 use tauri::AppHandle;
 use tokio::sync::RwLock;
 
 use crate::ab_test_selector::ABTestSelector;
 use crate::app_in_memory_config::{MinerType, DEFAULT_EXCHANGE_ID};
+use crate::node::node_config_overrides::NodeConfigOverrides;
 use crate::node::node_manager::NodeType;
+use crate::node::orphan_chain_recovery::OrphanChainRecoveryConfig;
 use crate::utils::rand_utils;
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
@@ -68,6 +70,10 @@ pub struct ConfigCoreContent {
     last_changelog_version: Version,
     airdrop_tokens: Option<AirdropTokens>,
     remote_base_node_address: String,
+    chain_tip_verification_nodes: Vec<String>,
+    node_data_dir: Option<PathBuf>,
+    node_config_overrides: NodeConfigOverrides,
+    orphan_chain_recovery: OrphanChainRecoveryConfig,
     node_type: NodeType,
     exchange_id: String,
 }
@@ -123,6 +129,10 @@ impl Default for ConfigCoreContent {
             last_changelog_version: Version::new(0, 0, 0),
             airdrop_tokens: None,
             remote_base_node_address,
+            chain_tip_verification_nodes: vec![],
+            node_data_dir: None,
+            node_config_overrides: NodeConfigOverrides::default(),
+            orphan_chain_recovery: OrphanChainRecoveryConfig::default(),
             node_type: NodeType::default(),
             exchange_id: DEFAULT_EXCHANGE_ID.to_string(),
         }
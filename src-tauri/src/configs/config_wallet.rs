@@ -33,11 +33,16 @@ use crate::{
     configs::config_ui::{ConfigUI, ConfigUIContent},
     internal_wallet::TariWalletDetails,
     pin::PinLockerState,
+    wallet::{
+        auto_sweep::{AutoSweepLogEntry, AutoSweepRule, MAX_AUTO_SWEEP_LOG_ENTRIES},
+        payment_requests::PaymentRequest,
+        watch_only::WatchOnlyPackage,
+    },
 };
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 
-static EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK: &str = "Exchanges";
+pub(crate) static EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK: &str = "Exchanges";
 
 static LOG_TARGET: &str = "tari::universe::config_wallet";
 
@@ -46,10 +51,60 @@ static INSTANCE: LazyLock<RwLock<ConfigWallet>> =
 
 pub const WALLET_VERSION: i32 = 2;
 
+const LEGACY_WALLET_DATA_DIR_NAME: &str = "wallet";
+pub const INTERNAL_WALLETS_DATA_DIR_NAME: &str = "wallets";
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ExternalTariAddressBookRecord {
     pub name: String,
     pub address: TariAddress,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub default_payment_id: Option<String>,
+    #[serde(default)]
+    pub last_used_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InternalWalletRecord {
+    pub id: WalletId,
+    pub name: String,
+    // Relative to the app data dir, the first wallet keeps the legacy "wallet" folder
+    pub data_dir_name: String,
+    // State below is only up to date for wallets that are not selected, the top level fields
+    // hold the state of the selected wallet
+    #[serde(default)]
+    pub tari_wallet_details: Option<TariWalletDetails>,
+    #[serde(default)]
+    pub pin_locker_state: PinLockerState,
+    #[serde(default)]
+    pub seed_backed_up: bool,
+    #[serde(default)]
+    pub last_known_balance: MicroMinotari,
+}
+
+impl InternalWalletRecord {
+    /// Why deleting the wallet could lose funds, if it could
+    pub fn deletion_warning(&self) -> Option<String> {
+        match (
+            self.seed_backed_up,
+            self.last_known_balance > MicroMinotari(0),
+        ) {
+            (false, true) => Some(format!(
+                "{} holds {} and its seed words were never backed up",
+                self.name, self.last_known_balance
+            )),
+            (false, false) => Some(format!(
+                "The seed words of {} were never backed up",
+                self.name
+            )),
+            (true, true) => Some(format!("{} holds {}", self.name, self.last_known_balance)),
+            (true, false) => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -98,6 +153,20 @@ pub struct ConfigWalletContent {
     last_known_balance: MicroMinotari,
     #[getset(get = "pub", set = "pub")]
     security_warning_dismissed: bool,
+    #[getset(get = "pub", set = "pub")]
+    auto_sweep_rules: Vec<AutoSweepRule>,
+    #[getset(get = "pub")]
+    auto_sweep_log: Vec<AutoSweepLogEntry>,
+    // Only the core phase runs, used on an air-gapped machine that signs offline transactions
+    #[getset(get = "pub", set = "pub")]
+    signer_only_mode: bool,
+    // Same order as `tari_wallets`, may be missing for wallets created before multiple wallets were supported
+    internal_wallets: Vec<InternalWalletRecord>,
+    #[getset(get = "pub")]
+    payment_requests: Vec<PaymentRequest>,
+    // Scans the imported address instead of the owned wallets, spending is disabled
+    #[getset(get = "pub", set = "pub")]
+    watch_only_package: Option<WatchOnlyPackage>,
 }
 
 impl Default for ConfigWalletContent {
@@ -118,6 +187,12 @@ impl Default for ConfigWalletContent {
             seed_backed_up: false,
             last_known_balance: MicroMinotari(0),
             security_warning_dismissed: false,
+            auto_sweep_rules: Vec::new(),
+            auto_sweep_log: Vec::new(),
+            signer_only_mode: false,
+            internal_wallets: Vec::new(),
+            payment_requests: Vec::new(),
+            watch_only_package: None,
         }
     }
 }
@@ -150,25 +225,227 @@ impl ConfigWalletContent {
             ExternalTariAddressBookRecord {
                 name: EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK.to_string(),
                 address,
+                labels: vec![],
+                notes: String::new(),
+                default_payment_id: None,
+                last_used_at: None,
             },
         );
         // Don't clear tari_wallet_details
         self
     }
 
+    /// Inserts or replaces a contact, `previous_name` is removed first when a contact is renamed
+    pub fn save_contact(
+        &mut self,
+        payload: (Option<String>, ExternalTariAddressBookRecord),
+    ) -> &mut Self {
+        let (previous_name, record) = payload;
+        if let Some(previous_name) = previous_name {
+            self.external_tari_addresses_book.remove(&previous_name);
+        }
+        self.external_tari_addresses_book
+            .insert(record.name.clone(), record);
+        self
+    }
+
+    pub fn remove_contact(&mut self, name: String) -> &mut Self {
+        self.external_tari_addresses_book.remove(&name);
+        self
+    }
+
+    pub fn mark_contact_used(&mut self, payload: (TariAddress, u64)) -> &mut Self {
+        let (address, timestamp) = payload;
+        for record in self.external_tari_addresses_book.values_mut() {
+            if record.address == address {
+                record.last_used_at = Some(timestamp);
+            }
+        }
+        self
+    }
+
+    /// Stores the outcome of a sweep and restarts the interval of its rule
+    pub fn record_auto_sweep(&mut self, entry: AutoSweepLogEntry) -> &mut Self {
+        if let Some(rule) = self
+            .auto_sweep_rules
+            .iter_mut()
+            .find(|rule| rule.id == entry.rule_id)
+        {
+            rule.last_attempt_at = Some(entry.timestamp);
+        }
+        self.auto_sweep_log.push(entry);
+        let overflow = self
+            .auto_sweep_log
+            .len()
+            .saturating_sub(MAX_AUTO_SWEEP_LOG_ENTRIES);
+        self.auto_sweep_log.drain(..overflow);
+        self
+    }
+
+    pub fn add_payment_request(&mut self, request: PaymentRequest) -> &mut Self {
+        self.payment_requests.push(request);
+        self
+    }
+
+    pub fn remove_payment_request(&mut self, payment_id: String) -> &mut Self {
+        self.payment_requests
+            .retain(|request| request.payment_id != payment_id);
+        self
+    }
+
+    /// Replaces the stored requests that have the same payment id
+    pub fn update_payment_requests(&mut self, requests: Vec<PaymentRequest>) -> &mut Self {
+        for request in requests {
+            if let Some(stored) = self
+                .payment_requests
+                .iter_mut()
+                .find(|stored| stored.payment_id == request.payment_id)
+            {
+                *stored = request;
+            }
+        }
+        self
+    }
+
     // Auto select the first wallet
     pub fn add_tari_wallet(&mut self, selected_wallet_details: TariWalletDetails) -> &mut Self {
+        self.stash_selected_wallet_state();
         // Deselect the external Tari address because a new address is now selected by default
         self.selected_external_tari_address = None;
+
+        let data_dir_name = if self
+            .internal_wallets
+            .iter()
+            .any(|record| record.data_dir_name == LEGACY_WALLET_DATA_DIR_NAME)
+        {
+            internal_wallet_data_dir_name(&selected_wallet_details.id)
+        } else {
+            LEGACY_WALLET_DATA_DIR_NAME.to_string()
+        };
+        // A new seed is encrypted with the PIN of the previous wallet, only the lockout starts over
+        self.pin_locker_state.reset_pin_attempts();
+        self.internal_wallets.insert(
+            0,
+            InternalWalletRecord {
+                id: selected_wallet_details.id.clone(),
+                name: format!("Wallet {}", self.internal_wallets.len() + 1),
+                data_dir_name,
+                tari_wallet_details: Some(selected_wallet_details.clone()),
+                pin_locker_state: self.pin_locker_state.clone(),
+                seed_backed_up: false,
+                last_known_balance: MicroMinotari(0),
+            },
+        );
         self.tari_wallets
             .insert(0, selected_wallet_details.id.clone());
         self.tari_wallet_details = Some(selected_wallet_details);
+        self.last_known_balance = MicroMinotari(0);
 
         // Remove when we decide not to autoselect
         self.seed_backed_up = false;
 
         self
     }
+
+    /// Records of all owned wallets, the selected wallet comes first
+    pub fn internal_wallet_records(&self) -> Vec<InternalWalletRecord> {
+        self.tari_wallets
+            .iter()
+            .enumerate()
+            .map(|(index, id)| {
+                self.internal_wallets
+                    .iter()
+                    .find(|record| &record.id == id)
+                    .cloned()
+                    .unwrap_or_else(|| InternalWalletRecord {
+                        id: id.clone(),
+                        name: format!("Wallet {}", index + 1),
+                        // Only the selected wallet has data in the legacy folder
+                        data_dir_name: if index == 0 {
+                            LEGACY_WALLET_DATA_DIR_NAME.to_string()
+                        } else {
+                            internal_wallet_data_dir_name(id)
+                        },
+                        tari_wallet_details: None,
+                        pin_locker_state: PinLockerState::default(),
+                        seed_backed_up: false,
+                        last_known_balance: MicroMinotari(0),
+                    })
+            })
+            .collect()
+    }
+
+    pub fn selected_wallet_data_dir_name(&self) -> String {
+        if let Some(package) = &self.watch_only_package {
+            return format!(
+                "{INTERNAL_WALLETS_DATA_DIR_NAME}/{}",
+                package.data_dir_name()
+            );
+        }
+        self.internal_wallet_records()
+            .first()
+            .map_or(LEGACY_WALLET_DATA_DIR_NAME.to_string(), |record| {
+                record.data_dir_name.clone()
+            })
+    }
+
+    /// Moves `wallet_id` to the front of the owned wallets and swaps in its PIN, backup and balance state
+    pub fn select_internal_wallet(&mut self, wallet_id: WalletId) -> &mut Self {
+        self.stash_selected_wallet_state();
+        let Some(index) = self.tari_wallets.iter().position(|id| id == &wallet_id) else {
+            return self;
+        };
+
+        let id = self.tari_wallets.remove(index);
+        self.tari_wallets.insert(0, id);
+        let record = self.internal_wallets.remove(index);
+        self.selected_external_tari_address = None;
+        self.tari_wallet_details = record.tari_wallet_details.clone();
+        self.pin_locker_state = record.pin_locker_state.clone();
+        self.seed_backed_up = record.seed_backed_up;
+        self.last_known_balance = record.last_known_balance;
+        self.internal_wallets.insert(0, record);
+        self
+    }
+
+    pub fn rename_internal_wallet(&mut self, payload: (WalletId, String)) -> &mut Self {
+        let (wallet_id, name) = payload;
+        self.internal_wallets = self.internal_wallet_records();
+        if let Some(record) = self
+            .internal_wallets
+            .iter_mut()
+            .find(|record| record.id == wallet_id)
+        {
+            record.name = name;
+        }
+        self
+    }
+
+    /// Forgets a wallet that is not selected
+    pub fn remove_internal_wallet(&mut self, wallet_id: WalletId) -> &mut Self {
+        self.internal_wallets = self.internal_wallet_records();
+        if let Some(index) = self.tari_wallets.iter().position(|id| id == &wallet_id) {
+            if index > 0 {
+                self.tari_wallets.remove(index);
+                self.internal_wallets.remove(index);
+            }
+        }
+        self
+    }
+
+    fn stash_selected_wallet_state(&mut self) {
+        self.internal_wallets = self.internal_wallet_records();
+        if let Some(record) = self.internal_wallets.first_mut() {
+            record.tari_wallet_details = self.tari_wallet_details.clone();
+            record.pin_locker_state = self.pin_locker_state.clone();
+            record.seed_backed_up = self.seed_backed_up;
+            record.last_known_balance = self.last_known_balance;
+        }
+    }
+}
+
+fn internal_wallet_data_dir_name(wallet_id: &WalletId) -> String {
+    format!("{INTERNAL_WALLETS_DATA_DIR_NAME}/{}", wallet_id.as_str())
 }
 
 pub struct ConfigWallet {
@@ -278,3 +555,88 @@ impl ConfigImpl for ConfigWallet {
         &mut self.content
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    const ADDRESS: &str = "f25eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF3g";
+
+    fn wallet_details(id: &str) -> TariWalletDetails {
+        TariWalletDetails {
+            id: WalletId::new(id.to_string()),
+            tari_address: TariAddress::from_str(ADDRESS).unwrap(),
+            wallet_birthday: 0,
+            view_private_key_hex: String::new(),
+            spend_public_key_hex: String::new(),
+        }
+    }
+
+    #[test]
+    fn legacy_wallet_keeps_its_data_dir() {
+        let mut config = ConfigWalletContent::default();
+        config.tari_wallets = vec![WalletId::new("legacy".to_string())];
+
+        assert_eq!(config.selected_wallet_data_dir_name(), "wallet");
+
+        config.add_tari_wallet(wallet_details("second"));
+        let records = config.internal_wallet_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.first().unwrap().data_dir_name, "wallets/second");
+        assert_eq!(records.get(1).unwrap().data_dir_name, "wallet");
+    }
+
+    #[test]
+    fn switching_swaps_per_wallet_state() {
+        let mut config = ConfigWalletContent::default();
+        config.add_tari_wallet(wallet_details("first"));
+        config.seed_backed_up = true;
+        config.pin_locker_state.set_pin_locked(true);
+        config.add_tari_wallet(wallet_details("second"));
+
+        assert!(!config.seed_backed_up);
+        assert!(*config.pin_locker_state.pin_locked());
+
+        config.seed_backed_up = false;
+        config.pin_locker_state.set_pin_locked(false);
+        config.select_internal_wallet(WalletId::new("first".to_string()));
+
+        assert_eq!(config.tari_wallets.first().unwrap().as_str(), "first");
+        assert!(config.seed_backed_up);
+        assert!(*config.pin_locker_state.pin_locked());
+        assert_eq!(config.selected_wallet_data_dir_name(), "wallet");
+
+        config.select_internal_wallet(WalletId::new("second".to_string()));
+        assert!(!*config.pin_locker_state.pin_locked());
+    }
+
+    #[test]
+    fn selected_wallet_cannot_be_removed() {
+        let mut config = ConfigWalletContent::default();
+        config.add_tari_wallet(wallet_details("first"));
+        config.add_tari_wallet(wallet_details("second"));
+
+        config.remove_internal_wallet(WalletId::new("second".to_string()));
+        assert_eq!(config.tari_wallets.len(), 2);
+
+        config.remove_internal_wallet(WalletId::new("first".to_string()));
+        assert_eq!(config.internal_wallet_records().len(), 1);
+    }
+
+    #[test]
+    fn warns_before_deleting_unbacked_or_funded_wallets() {
+        let mut config = ConfigWalletContent::default();
+        config.add_tari_wallet(wallet_details("first"));
+        config.add_tari_wallet(wallet_details("second"));
+        let mut record = config.internal_wallet_records().get(1).unwrap().clone();
+
+        assert!(record.deletion_warning().is_some());
+        record.seed_backed_up = true;
+        assert!(record.deletion_warning().is_none());
+        record.last_known_balance = MicroMinotari(1);
+        assert!(record.deletion_warning().is_some());
+    }
+}
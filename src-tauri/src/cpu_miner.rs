@@ -27,9 +27,15 @@ use crate::configs::config_wallet::ConfigWalletContent;
 use crate::configs::pools::cpu_pools::CpuPool;
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
+use crate::node::block_explorer::BlockPowAlgorithm;
+use crate::node::network_stats_history::{NetworkStatsHistory, HASHRATE_AVERAGE_WINDOW};
 use crate::pool_status_watcher::{LuckyPoolAdapter, PoolApiAdapters, SupportXmrPoolAdapter};
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
+use crate::solo_mining_estimator::{
+    estimate_solo_mining, is_solo_mining, SoloMiningEstimate, SoloMiningLuckTracker,
+    ALGORITHM_BLOCK_TIME_SECS,
+};
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::math_utils::estimate_earning;
 use crate::xmrig::http_api::models::Summary;
@@ -358,8 +364,24 @@ impl CpuMiner {
                         let cpu_status = match xmrig_summary {
                             Some(xmrig_status) => {
                                 let hash_rate = xmrig_status.hashrate.total[0].unwrap_or_default();
+                                let network_hashrate = NetworkStatsHistory::current()
+                                    .read()
+                                    .await
+                                    .average_hashrate(BlockPowAlgorithm::RandomXM, HASHRATE_AVERAGE_WINDOW)
+                                    .unwrap_or(node_status.monero_randomx_network_hashrate);
                                 let estimated_earnings =
-                                    estimate_earning(node_status.monero_randomx_network_hashrate, hash_rate, node_status.block_reward);
+                                    estimate_earning(network_hashrate, hash_rate, node_status.block_reward);
+                                // Pool miners are paid by shares, time to block only matters when mining solo
+                                let solo_mining_estimate = if is_solo_mining(BlockPowAlgorithm::RandomXM).await {
+                                    let mut luck_tracker = SoloMiningLuckTracker::current().write().await;
+                                    luck_tracker.record_hashrate(BlockPowAlgorithm::RandomXM, hash_rate, network_hashrate);
+                                    Some(SoloMiningEstimate {
+                                        luck: luck_tracker.luck(BlockPowAlgorithm::RandomXM),
+                                        ..estimate_solo_mining(hash_rate, network_hashrate, ALGORITHM_BLOCK_TIME_SECS)
+                                    })
+                                } else {
+                                    None
+                                };
 
                                 // // UNUSED, commented for now
                                 // let hasrate_sum = xmrig_status
@@ -377,6 +399,7 @@ impl CpuMiner {
                                     estimated_earnings: MicroMinotari(estimated_earnings).as_u64(),
                                     connection: CpuMinerConnectionStatus { is_connected },
                                     pool_status: last_pool_status.clone(),
+                                    solo_mining_estimate,
                                 }
                             }
                             None => {
@@ -31,12 +31,18 @@ use crate::{
     internal_wallet::TariAddressType,
     node::{node_adapter::NodeIdentity, node_manager::NodeType},
     setup::{listeners::AppModule, setup_manager::SetupPhase},
+    utils::sync_progress::SyncRate,
     wallet::wallet_types::{TransactionInfo, WalletBalance},
 };
 
 #[derive(Clone, Debug, Serialize)]
 pub enum EventType {
     WalletBalanceUpdate,
+    TransactionHistoryUpdate,
+    AutoSweepUpdate,
+    CoinbaseRewardsMatured,
+    WalletRescanUpdate,
+    PaymentRequestUpdate,
     BaseNodeUpdate,
     GpuDevicesUpdate,
     CpuPoolStatsUpdate,
@@ -54,6 +60,9 @@ pub enum EventType {
     #[cfg(target_os = "windows")]
     SystemDependenciesLoaded,
     StuckOnOrphanChain,
+    ChainTipVerification,
+    MinorityChainMiningStopped,
+    OrphanChainRecoveryUpdate,
     NetworkStatus,
     NodeTypeUpdate,
     ConfigCoreLoaded,
@@ -62,6 +71,8 @@ pub enum EventType {
     ConfigMiningLoaded,
     ConfigPoolsLoaded,
     BackgroundNodeSyncUpdate,
+    NodeDatabaseMoveProgress,
+    NodeSnapshotBootstrapResult,
     InitWalletScanningProgress,
     ConnectionStatus,
     ExchangeIdChanged,
@@ -160,11 +171,37 @@ pub struct NodeTypeUpdatePayload {
     pub node_connection_address: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "step")]
+pub enum BackgroundNodeSyncUpdatePayload {
+    Startup {
+        initial_connected_peers: u64,
+        required_peers: u32,
+    },
+    Header {
+        local_header_height: u64,
+        tip_header_height: u64,
+        local_block_height: u64,
+        tip_block_height: u64,
+        headers_rate: Option<SyncRate>,
+    },
+    Block {
+        local_header_height: u64,
+        tip_header_height: u64,
+        local_block_height: u64,
+        tip_block_height: u64,
+        blocks_rate: Option<SyncRate>,
+    },
+    Done,
+    Unknown,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct InitWalletScanningProgressPayload {
     pub scanned_height: u64,
     pub total_height: u64,
     pub progress: f64,
+    pub scan_rate: Option<SyncRate>,
 }
 
 // TODO: Bring back connection status callback, was removed with removing setup screen and related logic
@@ -22,14 +22,23 @@ use crate::configs::config_mining::GpuDevicesSettings;
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 use crate::configs::config_ui::WalletUIMode;
 use crate::events::{
-    ConnectionStatusPayload, CriticalProblemPayload, DisabledPhasesPayload,
-    InitWalletScanningProgressPayload, UpdateAppModuleStatusPayload,
+    BackgroundNodeSyncUpdatePayload, ConnectionStatusPayload, CriticalProblemPayload,
+    DisabledPhasesPayload, InitWalletScanningProgressPayload, UpdateAppModuleStatusPayload,
 };
 use crate::gpu_devices::GpuDeviceInformation;
 use crate::internal_wallet::TariAddressType;
+use crate::node::chain_tip_verifier::{ChainTipVerification, MinorityChainMiningStoppedPayload};
+use crate::node::database_relocation::NodeDatabaseMoveProgressPayload;
+use crate::node::orphan_chain_recovery::OrphanChainRecoveryStep;
+use crate::node::snapshot_bootstrap::SnapshotBootstrapResultPayload;
 use crate::pool_status_watcher::PoolStatus;
 #[cfg(target_os = "windows")]
 use crate::system_dependencies::UniversalSystemDependency;
+use crate::wallet::auto_sweep::AutoSweepLogEntry;
+use crate::wallet::coinbase_maturity::CoinbaseRewardsMaturedPayload;
+use crate::wallet::payment_requests::PaymentRequest;
+use crate::wallet::transaction_history::TransactionHistoryUpdatePayload;
+use crate::wallet::wallet_rescan::WalletRescanStep;
 use crate::wallet::wallet_types::{TransactionInfo, WalletBalance};
 use crate::{
     commands::CpuMinerStatus,
@@ -44,11 +53,10 @@ use crate::{
     },
     hardware::hardware_status_monitor::PublicDeviceGpuProperties,
     setup::setup_manager::SetupPhase,
-    utils::app_flow_utils::FrontendReadyChannel,
+    utils::{app_flow_utils::FrontendReadyChannel, sync_progress::SyncRate},
     BaseNodeStatus, GpuMinerStatus,
 };
 use log::error;
-use std::collections::HashMap;
 use std::sync::LazyLock;
 use tari_common_types::tari_address::TariAddress;
 use tauri::{AppHandle, Emitter};
@@ -115,6 +123,76 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_chain_tip_verification(payload: ChainTipVerification) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::ChainTipVerification,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit ChainTipVerification event: {e:?}");
+        }
+    }
+
+    pub async fn emit_minority_chain_mining_stopped(payload: MinorityChainMiningStoppedPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::MinorityChainMiningStopped,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit MinorityChainMiningStopped event: {e:?}");
+        }
+    }
+
+    pub async fn emit_orphan_chain_recovery_update(payload: OrphanChainRecoveryStep) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::OrphanChainRecoveryUpdate,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit OrphanChainRecoveryUpdate event: {e:?}");
+        }
+    }
+
+    pub async fn emit_node_database_move_progress(payload: NodeDatabaseMoveProgressPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::NodeDatabaseMoveProgress,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit NodeDatabaseMoveProgress event: {e:?}");
+        }
+    }
+
+    pub async fn emit_node_snapshot_bootstrap_result(payload: SnapshotBootstrapResultPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::NodeSnapshotBootstrapResult,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit NodeSnapshotBootstrapResult event: {e:?}");
+        }
+    }
+
     pub async fn emit_show_release_notes(payload: ShowReleaseNotesPayload) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
@@ -354,6 +432,76 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_transaction_history_update(payload: TransactionHistoryUpdatePayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::TransactionHistoryUpdate,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit TransactionHistoryUpdate event: {e:?}");
+        }
+    }
+
+    pub async fn emit_auto_sweep_update(payload: AutoSweepLogEntry) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::AutoSweepUpdate,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit AutoSweepUpdate event: {e:?}");
+        }
+    }
+
+    pub async fn emit_coinbase_rewards_matured(payload: CoinbaseRewardsMaturedPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::CoinbaseRewardsMatured,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit CoinbaseRewardsMatured event: {e:?}");
+        }
+    }
+
+    pub async fn emit_wallet_rescan_update(payload: WalletRescanStep) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::WalletRescanUpdate,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit WalletRescanUpdate event: {e:?}");
+        }
+    }
+
+    pub async fn emit_payment_request_update(payload: PaymentRequest) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::PaymentRequestUpdate,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET, "Failed to emit PaymentRequestUpdate event: {e:?}");
+        }
+    }
+
     pub async fn emit_base_node_update(status: BaseNodeStatus) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
@@ -490,7 +638,7 @@ impl EventsEmitter {
         }
     }
 
-    pub async fn emit_background_node_sync_update(payload: HashMap<String, String>) {
+    pub async fn emit_background_node_sync_update(payload: BackgroundNodeSyncUpdatePayload) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
             event_type: EventType::BackgroundNodeSyncUpdate,
@@ -508,6 +656,7 @@ impl EventsEmitter {
         scanned_height: u64,
         total_height: u64,
         progress: f64,
+        scan_rate: Option<SyncRate>,
     ) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
@@ -516,6 +665,7 @@ impl EventsEmitter {
                 scanned_height,
                 total_height,
                 progress,
+                scan_rate,
             },
         };
         if let Err(e) = Self::get_app_handle()
@@ -31,6 +31,7 @@ use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
 use crate::setup::listeners::SetupFeature;
 use crate::setup::setup_manager::SetupManager;
+use crate::solo_mining_estimator::{is_solo_mining, SoloMiningLuckTracker};
 use crate::{
     events::NodeTypeUpdatePayload, events_emitter::EventsEmitter, tasks_tracker::TasksTrackers,
     UniverseAppState,
@@ -83,6 +84,9 @@ impl EventsManager {
                             Some(balance),
                         )
                         .await;
+                        if coinbase_tx.is_some() {
+                            Self::record_solo_block_found(&app_clone, block_height).await;
+                        }
                         let allow_notifications = *ConfigCore::content().await.allow_notifications();
                         if coinbase_tx.is_some() && allow_notifications {
                             send_new_block_mined(app_clone.clone(), block_height).await;
@@ -110,6 +114,24 @@ impl EventsManager {
         });
     }
 
+    /// Counts a won block towards the luck of its algorithm when that algorithm is mined solo
+    async fn record_solo_block_found(app: &AppHandle, block_height: u64) {
+        let state = app.state::<UniverseAppState>();
+        let pow_algo = match state.node_manager.get_block_details(block_height).await {
+            Ok(details) => details.block.pow_algo,
+            Err(e) => {
+                error!(target: LOG_TARGET, "Failed to get the algorithm of won block #{block_height}: {e}");
+                return;
+            }
+        };
+        if is_solo_mining(pow_algo).await {
+            SoloMiningLuckTracker::current()
+                .write()
+                .await
+                .record_block_found(pow_algo);
+        }
+    }
+
     pub async fn handle_node_type_update(app_handle: &AppHandle) {
         let node_manager = &app_handle.state::<UniverseAppState>().node_manager;
         let node_type = Some(node_manager.get_node_type().await);
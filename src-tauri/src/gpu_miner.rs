@@ -37,7 +37,13 @@ use crate::binaries::{Binaries, BinaryResolver};
 use crate::events_emitter::EventsEmitter;
 use crate::gpu_miner_adapter::GpuNodeSource;
 use crate::gpu_status_file::{GpuDevice, GpuStatusFile};
+use crate::node::block_explorer::BlockPowAlgorithm;
+use crate::node::network_stats_history::{NetworkStatsHistory, HASHRATE_AVERAGE_WINDOW};
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
+use crate::solo_mining_estimator::{
+    estimate_solo_mining, is_solo_mining, SoloMiningEstimate, SoloMiningLuckTracker,
+    ALGORITHM_BLOCK_TIME_SECS,
+};
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::math_utils::estimate_earning;
 use crate::{
@@ -166,6 +172,11 @@ impl GpuMiner {
         Ok(())
     }
 
+    pub async fn is_running(&self) -> bool {
+        let lock = self.watcher.read().await;
+        lock.is_running()
+    }
+
     #[allow(dead_code)]
     pub async fn is_pid_file_exists(&self, base_path: PathBuf) -> bool {
         let lock = self.watcher.read().await;
@@ -293,14 +304,32 @@ impl GpuMiner {
 
                         let gpu_status = match gpu_raw_status {
                             Some(gpu_raw_status) => {
+                                let network_hashrate = NetworkStatsHistory::current()
+                                    .read()
+                                    .await
+                                    .average_hashrate(BlockPowAlgorithm::Sha3x, HASHRATE_AVERAGE_WINDOW)
+                                    .unwrap_or(node_status.sha_network_hashrate);
                                 let estimated_earnings = estimate_earning(
-                                    node_status.sha_network_hashrate,
+                                    network_hashrate,
                                     gpu_raw_status.hash_rate,
                                     node_status.block_reward,
                                 );
 
+                                // Pool miners are paid by shares, time to block only matters when mining solo
+                                let solo_mining_estimate = if is_solo_mining(BlockPowAlgorithm::Sha3x).await {
+                                    let mut luck_tracker = SoloMiningLuckTracker::current().write().await;
+                                    luck_tracker.record_hashrate(BlockPowAlgorithm::Sha3x, gpu_raw_status.hash_rate, network_hashrate);
+                                    Some(SoloMiningEstimate {
+                                        luck: luck_tracker.luck(BlockPowAlgorithm::Sha3x),
+                                        ..estimate_solo_mining(gpu_raw_status.hash_rate, network_hashrate, ALGORITHM_BLOCK_TIME_SECS)
+                                    })
+                                } else {
+                                    None
+                                };
+
                                 GpuMinerStatus {
                                     estimated_earnings: MicroMinotari(estimated_earnings).as_u64(),
+                                    solo_mining_estimate,
                                     ..gpu_raw_status
                                 }
                             }
@@ -25,6 +25,7 @@ use crate::gpu_status_file::GpuDevice;
 use crate::port_allocator::PortAllocator;
 use crate::process_adapter::HealthStatus;
 use crate::process_adapter::ProcessStartupSpec;
+use crate::solo_mining_estimator::SoloMiningEstimate;
 use anyhow::anyhow;
 use anyhow::Error;
 use async_trait::async_trait;
@@ -281,12 +282,14 @@ impl GpuMinerStatusMonitor {
                         is_mining: false,
                         hash_rate: 0.0,
                         estimated_earnings: 0,
+                        solo_mining_estimate: None,
                     });
                 }
                 return Ok(GpuMinerStatus {
                     is_mining: false,
                     hash_rate: 0.0,
                     estimated_earnings: 0,
+                    solo_mining_estimate: None,
                 });
             }
         };
@@ -299,6 +302,7 @@ impl GpuMinerStatusMonitor {
                     is_mining: false,
                     hash_rate: 0.0,
                     estimated_earnings: 0,
+                    solo_mining_estimate: None,
                 });
             }
         };
@@ -307,6 +311,7 @@ impl GpuMinerStatusMonitor {
             is_mining: true,
             estimated_earnings: 0,
             hash_rate: body.total_hashrate.ten_seconds.unwrap_or(0.0),
+            solo_mining_estimate: None,
         })
     }
 }
@@ -331,4 +336,5 @@ pub(crate) struct GpuMinerStatus {
     pub is_mining: bool,
     pub hash_rate: f64,
     pub estimated_earnings: u64,
+    pub solo_mining_estimate: Option<SoloMiningEstimate>,
 }
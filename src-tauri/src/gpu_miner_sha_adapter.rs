@@ -233,6 +233,7 @@ impl GpuMinerShaStatusMonitor {
                 is_mining: true,
                 estimated_earnings: 0,
                 hash_rate: status.current_hashrate as f64,
+                solo_mining_estimate: None,
             });
         }
 
@@ -240,6 +241,7 @@ impl GpuMinerShaStatusMonitor {
             is_mining: false,
             estimated_earnings: 0,
             hash_rate: 0.0,
+            solo_mining_estimate: None,
         })
     }
 }
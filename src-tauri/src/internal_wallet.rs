@@ -59,6 +59,7 @@ use crate::events::CriticalProblemPayload;
 use crate::events_emitter::EventsEmitter;
 use crate::pin::PinManager;
 use crate::utils::{cryptography, rand_utils};
+use crate::wallet::watch_only::WatchOnlyPackage;
 use crate::UniverseAppState;
 
 const LOG_TARGET: &str = "tari::universe::internal_wallet";
@@ -72,6 +73,15 @@ pub struct TariWalletDetails {
     pub spend_public_key_hex: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct InternalWalletInfo {
+    pub id: String,
+    pub name: String,
+    // Unknown until the wallet is selected for the first time
+    pub tari_address: Option<String>,
+    pub is_selected: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct InternalWallet {
     tari_address_type: TariAddressType,
@@ -155,6 +165,29 @@ impl InternalWallet {
         internal_wallet.post_init(app_handle).await
     }
 
+    /// Scans the address of an imported watch-only package, there is no seed to spend with
+    pub async fn initialize_watch_only(
+        app_handle: &tauri::AppHandle,
+        package: WatchOnlyPackage,
+    ) -> Result<(), anyhow::Error> {
+        package.validate(Network::get_current())?;
+        let tari_wallet_details = package.to_wallet_details()?;
+        let internal_wallet = InternalWallet {
+            tari_address_type: TariAddressType::Internal,
+            encrypted_tari_seed: Hidden::hide(None),
+            encrypted_monero_seed: Hidden::hide(None),
+            monero_address: ConfigWallet::content().await.monero_address().clone(),
+            external_tari_address: None,
+            tari_wallet_details: Some(tari_wallet_details),
+        };
+
+        internal_wallet.post_init(app_handle).await
+    }
+
+    pub async fn is_watch_only() -> bool {
+        ConfigWallet::content().await.watch_only_package().is_some()
+    }
+
     /** Ensures wallet config contains everything needed to initialize the wallet - returns false when impossible */
     pub async fn validate_wallet_config_for_seed(
         app_handle: &AppHandle,
@@ -345,10 +378,118 @@ impl InternalWallet {
         Ok((tari_wallet_details.id, tari_seed_binary))
     }
 
+    pub async fn list_tari_wallets() -> Vec<InternalWalletInfo> {
+        let config = ConfigWallet::content().await;
+        let is_internal = config.selected_external_tari_address().is_none();
+        config
+            .internal_wallet_records()
+            .into_iter()
+            .enumerate()
+            .map(|(index, record)| {
+                let tari_wallet_details = if index == 0 {
+                    config.tari_wallet_details().clone()
+                } else {
+                    record.tari_wallet_details
+                };
+                InternalWalletInfo {
+                    id: record.id.as_str().to_string(),
+                    name: record.name,
+                    tari_address: tari_wallet_details.map(|d| d.tari_address.to_base58()),
+                    is_selected: is_internal && index == 0,
+                }
+            })
+            .collect()
+    }
+
+    /// Creates a wallet with a new seed next to the existing ones and selects it
+    pub async fn create_tari_wallet(
+        app_handle: &AppHandle,
+        name: String,
+    ) -> Result<TariWalletDetails, anyhow::Error> {
+        if InternalWallet::is_watch_only().await {
+            return Err(anyhow!("Disable watch-only mode to manage wallets"));
+        }
+        let pin_password = PinManager::get_validated_pin_if_defined(app_handle).await?;
+        let (tari_wallet_details, _seed_binary) =
+            InternalWallet::add_tari_wallet(app_handle, CipherSeed::new(), pin_password).await?;
+        ConfigWallet::update_field(
+            ConfigWalletContent::rename_internal_wallet,
+            (tari_wallet_details.id.clone(), name),
+        )
+        .await?;
+
+        InternalWallet::initialize_with_seed(app_handle).await?;
+        Ok(tari_wallet_details)
+    }
+
+    pub async fn switch_tari_wallet(
+        app_handle: &AppHandle,
+        wallet_id: WalletId,
+    ) -> Result<(), anyhow::Error> {
+        if InternalWallet::is_watch_only().await {
+            return Err(anyhow!("Disable watch-only mode to manage wallets"));
+        }
+        if !ConfigWallet::content()
+            .await
+            .tari_wallets()
+            .contains(&wallet_id)
+        {
+            return Err(anyhow!("Wallet {} not found", wallet_id.as_str()));
+        }
+        log::info!(target: LOG_TARGET, "Switching to Tari Wallet with id: {wallet_id:?}");
+        ConfigWallet::update_field(ConfigWalletContent::select_internal_wallet, wallet_id).await?;
+
+        // Drops the cached seed of the previous wallet
+        InternalWallet::initialize_with_seed(app_handle).await
+    }
+
+    /// Removes the seed and the data folder of a wallet that is not selected. A wallet whose seed
+    /// was never backed up or that last held funds is only deleted once the user `confirmed` it.
+    pub async fn delete_tari_wallet(
+        app_handle: &AppHandle,
+        wallet_id: WalletId,
+        confirmed: bool,
+    ) -> Result<(), anyhow::Error> {
+        let records = ConfigWallet::content().await.internal_wallet_records();
+        let is_selected = records.first().is_some_and(|record| record.id == wallet_id);
+        if is_selected {
+            return Err(anyhow!("The selected wallet can't be deleted"));
+        }
+        let record = records
+            .into_iter()
+            .find(|record| record.id == wallet_id)
+            .ok_or_else(|| anyhow!("Wallet {} not found", wallet_id.as_str()))?;
+        if let Some(warning) = record.deletion_warning().filter(|_| !confirmed) {
+            return Err(anyhow!("{warning}, confirm to delete the wallet anyway"));
+        }
+        if let Some(pin_password) = PinManager::get_validated_pin_if_defined(app_handle).await? {
+            // The PIN is checked against the seed of the wallet that is deleted
+            InternalWallet::get_stored_tari_seed(app_handle, wallet_id.clone(), Some(pin_password))
+                .await?;
+        }
+
+        InternalWallet::remove_tari_wallet(wallet_id.clone())?;
+        ConfigWallet::update_field(ConfigWalletContent::remove_internal_wallet, wallet_id).await?;
+
+        // Configs and seeds are per network while the data folder holds every network, only the
+        // current one belongs to this wallet
+        let data_dir = app_handle
+            .path()
+            .app_local_data_dir()?
+            .join(&record.data_dir_name);
+        let network_data_dir = data_dir.join(Network::get_current().to_string().to_lowercase());
+        if network_data_dir.try_exists()? {
+            fs::remove_dir_all(&network_data_dir).await?;
+        }
+        if data_dir.try_exists()? && fs::read_dir(&data_dir).await?.next_entry().await?.is_none() {
+            fs::remove_dir(&data_dir).await?;
+        }
+        Ok(())
+    }
+
     // Internal method
     //
-    // Support only one wallet fow now
-    // * Define if we want to have one PIN for all wallets
+    // New wallets are encrypted with the current PIN, every wallet shares it
     async fn add_tari_wallet(
         app_handle: &AppHandle,
         tari_seed: CipherSeed, // decrypted seed
@@ -536,16 +677,39 @@ impl InternalWallet {
                 .await
                 .ok_or_else(|| anyhow!("Seedless Wallet does not support PIN enciphering"))?
                 .id;
-            let encrypted_tari_seed = tari_seed.encipher(Some(pin_password))?;
+            let encrypted_tari_seed = tari_seed.encipher(Some(pin_password.clone()))?;
             InternalWallet::set_credentials(
                 app_handle,
-                wallet_id,
+                wallet_id.clone(),
                 &Credential {
                     encrypted_seed: encrypted_tari_seed.clone(),
                 },
                 false,
             )
             .await?;
+
+            // The PIN protects every wallet, not only the selected one
+            let other_wallet_ids: Vec<WalletId> = ConfigWallet::content()
+                .await
+                .tari_wallets()
+                .iter()
+                .filter(|id| **id != wallet_id)
+                .cloned()
+                .collect();
+            for other_wallet_id in other_wallet_ids {
+                let other_tari_seed =
+                    InternalWallet::get_stored_tari_seed(app_handle, other_wallet_id.clone(), None)
+                        .await?;
+                InternalWallet::set_credentials(
+                    app_handle,
+                    other_wallet_id,
+                    &Credential {
+                        encrypted_seed: other_tari_seed.encipher(Some(pin_password.clone()))?,
+                    },
+                    false,
+                )
+                .await?;
+            }
             encrypted_tari_seed
         };
         PinManager::set_pin_locked().await?;
@@ -794,6 +958,9 @@ impl InternalWallet {
     pub async fn get_tari_seed(
         pin_password: Option<SafePassword>,
     ) -> Result<CipherSeed, anyhow::Error> {
+        if InternalWallet::is_watch_only().await {
+            return Err(anyhow!("Spending is disabled in watch-only mode"));
+        }
         let encrypted_tari_seed = {
             let state_result = if InternalWallet::is_initialized() {
                 let internal_wallet = InternalWallet::current().read().await;
@@ -847,6 +1014,25 @@ impl InternalWallet {
         }
     }
 
+    /// Reads the seed of any wallet from the credential manager, not only the selected one
+    async fn get_stored_tari_seed(
+        app_handle: &AppHandle,
+        wallet_id: WalletId,
+        pin_password: Option<SafePassword>,
+    ) -> Result<CipherSeed, anyhow::Error> {
+        let encrypted_tari_seed = InternalWallet::get_credentials(app_handle, wallet_id, false)
+            .await?
+            .encrypted_seed;
+        if let Some(pin_password) = pin_password {
+            CipherSeed::from_enciphered_bytes(&encrypted_tari_seed, Some(pin_password))
+                .map_err(|_| anyhow!("Wrong PIN entered!"))
+        } else {
+            // Seed not yet encrypted with PIN
+            CipherSeed::from_binary(&encrypted_tari_seed)
+                .map_err(|_| anyhow!("Could not parse Tari Seed from binary"))
+        }
+    }
+
     /** Method safe to use before init - fallbacks to the credential manager */
     pub async fn get_monero_seed(
         pin_password: Option<SafePassword>,
@@ -39,6 +39,7 @@ use pool_status_watcher::{PoolStatus, PoolStatusWatcher};
 use process_stats_collector::ProcessStatsCollectorBuilder;
 
 use node::remote_node_adapter::RemoteNodeAdapter;
+use node::snapshot_bootstrap::SnapshotSource;
 
 use setup::setup_manager::SetupManager;
 use std::fs::{remove_dir_all, remove_file};
@@ -55,7 +56,6 @@ use websocket_events_manager::WebsocketEventsManager;
 use websocket_manager::{WebsocketManager, WebsocketManagerStatusMessage, WebsocketMessage};
 
 use log4rs::config::RawConfig;
-use std::fs;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tari_common::configuration::Network;
@@ -128,6 +128,7 @@ mod progress_trackers;
 mod release_notes;
 mod requests;
 mod setup;
+mod solo_mining_estimator;
 mod system_dependencies;
 mod systemtray_manager;
 mod tapplets;
@@ -451,43 +452,19 @@ fn main() {
                         if let Some(backup_path) = backup_path.value.as_str() {
                             info!(
                                 target: LOG_TARGET,
-                                "Trying to copy backup to existing db: {backup_path:?}"
+                                "Queueing backup import for the local node: {backup_path:?}"
                             );
                             let backup_path = Path::new(backup_path);
                             if backup_path.exists() {
-                                let existing_db = app
-                                    .path()
-                                    .app_local_data_dir()
-                                    .map_err(Box::new)?
-                                    .join("node")
-                                    .join(
-                                        Network::get_current_or_user_setting_or_default()
-                                            .to_string(),
-                                    )
-                                    .join("data")
-                                    .join("base_node")
-                                    .join("db");
-
-                                info!(target: LOG_TARGET, "Existing db path: {existing_db:?}");
-                                let _unused = fs::remove_dir_all(&existing_db).inspect_err(|e| {
-                                    warn!(
-                                        target: LOG_TARGET,
-                                        "Could not remove existing db when importing backup: {e:?}"
-                                    )
-                                });
-                                let _unused = fs::create_dir_all(&existing_db).inspect_err(|e| {
-                                    error!(
-                                        target: LOG_TARGET,
-                                        "Could not create existing db when importing backup: {e:?}"
-                                    )
-                                });
-                                let _unused = fs::copy(backup_path, existing_db.join("data.mdb"))
-                                    .inspect_err(|e| {
-                                        error!(
-                                            target: LOG_TARGET,
-                                            "Could not copy backup to existing db: {e:?}"
-                                        )
-                                    });
+                                let state = app.state::<UniverseAppState>();
+                                block_on(state.node_manager.set_pending_snapshot(
+                                    SnapshotSource::Import {
+                                        archive_path: backup_path.to_path_buf(),
+                                        manifest_path: None,
+                                        // Backups passed on the command line never came with a manifest
+                                        allow_unverified: true,
+                                    },
+                                ));
                             } else {
                                 warn!(
                                     target: LOG_TARGET,
@@ -559,8 +536,17 @@ fn main() {
             commands::get_tor_config,
             commands::get_tor_entry_guards,
             commands::get_transactions,
+            commands::get_transaction_history,
+            commands::search_transactions,
+            commands::export_transaction_history,
+            commands::get_coinbase_maturity_schedule,
             commands::import_seed_words,
             commands::revert_to_internal_wallet,
+            commands::list_internal_wallets,
+            commands::create_internal_wallet,
+            commands::rename_internal_wallet,
+            commands::delete_internal_wallet,
+            commands::switch_internal_wallet,
             commands::log_web_message,
             commands::open_log_dir,
             commands::reset_settings,
@@ -611,6 +597,27 @@ fn main() {
             commands::websocket_get_status,
             commands::reconnect,
             commands::send_one_sided_to_stealth_address,
+            commands::estimate_transaction_fee,
+            commands::send_batch_payment,
+            commands::prepare_offline_transaction,
+            commands::sign_offline_transaction,
+            commands::broadcast_offline_signed_transaction,
+            commands::cancel_offline_transaction,
+            commands::cancel_transaction,
+            commands::rebroadcast_transaction,
+            commands::get_pending_offline_transactions,
+            commands::set_signer_only_mode,
+            commands::export_watch_only_package,
+            commands::enable_watch_only_mode,
+            commands::disable_watch_only_mode,
+            commands::get_auto_sweep_rules,
+            commands::set_auto_sweep_rules,
+            commands::get_auto_sweep_log,
+            commands::get_contacts,
+            commands::save_contact,
+            commands::remove_contact,
+            commands::export_contacts,
+            commands::import_contacts,
             commands::verify_address_for_send,
             commands::validate_minotari_amount,
             commands::trigger_phases_restart,
@@ -620,6 +627,7 @@ fn main() {
             commands::get_bridge_envs,
             commands::parse_tari_address,
             commands::refresh_wallet_history,
+            commands::rescan_wallet,
             commands::get_base_node_status,
             commands::create_pin,
             commands::forgot_pin,
@@ -627,6 +635,9 @@ fn main() {
             commands::select_mining_mode,
             commands::update_custom_mining_mode,
             commands::encode_payment_id_to_address,
+            commands::create_payment_request,
+            commands::get_payment_requests,
+            commands::delete_payment_request,
             commands::save_wxtm_address,
             commands::set_security_warning_dismissed,
             commands::change_cpu_pool,
@@ -637,6 +648,21 @@ fn main() {
             commands::reset_cpu_pool_config,
             commands::restart_phases,
             commands::list_connected_peers,
+            commands::get_connected_peers_info,
+            commands::get_recent_blocks,
+            commands::get_block_details,
+            commands::get_network_stats_history,
+            commands::get_solo_mining_estimate,
+            commands::get_mempool_stats,
+            commands::get_mempool_transaction_status,
+            commands::set_orphan_chain_recovery_config,
+            commands::confirm_orphan_chain_resync,
+            commands::verify_chain_tip,
+            commands::set_chain_tip_verification_nodes,
+            commands::bootstrap_node_from_snapshot,
+            commands::import_node_snapshot,
+            commands::move_node_database,
+            commands::set_node_config_overrides,
             commands::set_feedback_fields,
         ])
         .build(tauri::generate_context!())
@@ -0,0 +1,138 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashSet;
+
+use minotari_node_grpc_client::grpc::{BlockHeader, BlockHeaderResponse, HistoricalBlock};
+use serde::Serialize;
+use tari_transaction_components::tari_amount::MicroMinotari;
+
+pub const DEFAULT_RECENT_BLOCKS_COUNT: u64 = 20;
+pub const MAX_RECENT_BLOCKS_COUNT: u64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum BlockPowAlgorithm {
+    RandomXM,
+    Sha3x,
+    RandomXT,
+    Unknown(u64),
+}
+
+impl From<u64> for BlockPowAlgorithm {
+    fn from(pow_algo: u64) -> Self {
+        match pow_algo {
+            0 => Self::RandomXM,
+            1 => Self::Sha3x,
+            2 => Self::RandomXT,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplorerBlock {
+    pub height: u64,
+    pub hash: String,
+    pub timestamp: u64,
+    pub pow_algo: BlockPowAlgorithm,
+    pub difficulty: u64,
+    pub reward: MicroMinotari,
+    pub num_transactions: u32,
+    pub is_won_by_wallet: bool,
+}
+
+impl ExplorerBlock {
+    pub fn from_header_response(header_response: BlockHeaderResponse) -> Option<Self> {
+        let header = header_response.header?;
+        Some(Self {
+            height: header.height,
+            hash: hex::encode(&header.hash),
+            timestamp: header.timestamp,
+            pow_algo: pow_algorithm(&header),
+            difficulty: header_response.difficulty,
+            reward: MicroMinotari::from(header_response.reward),
+            num_transactions: header_response.num_transactions,
+            is_won_by_wallet: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplorerBlockDetails {
+    #[serde(flatten)]
+    pub block: ExplorerBlock,
+    pub prev_hash: String,
+    pub confirmations: u64,
+    pub version: u32,
+    pub nonce: u64,
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+    pub num_kernels: usize,
+}
+
+impl ExplorerBlockDetails {
+    pub fn from_historical_block(
+        historical_block: HistoricalBlock,
+        header_response: BlockHeaderResponse,
+    ) -> Option<Self> {
+        let block = historical_block.block?;
+        let header = block.header?;
+        let body = block.body.unwrap_or_default();
+        Some(Self {
+            block: ExplorerBlock {
+                height: header.height,
+                hash: hex::encode(&header.hash),
+                timestamp: header.timestamp,
+                pow_algo: pow_algorithm(&header),
+                difficulty: header_response.difficulty,
+                reward: MicroMinotari::from(header_response.reward),
+                num_transactions: header_response.num_transactions,
+                is_won_by_wallet: false,
+            },
+            prev_hash: hex::encode(&header.prev_hash),
+            confirmations: historical_block.confirmations,
+            version: header.version,
+            nonce: header.nonce,
+            num_inputs: body.inputs.len(),
+            num_outputs: body.outputs.len(),
+            num_kernels: body.kernels.len(),
+        })
+    }
+}
+
+/// Marks the blocks this wallet received a coinbase for
+pub fn mark_won_blocks<'a>(
+    blocks: impl IntoIterator<Item = &'a mut ExplorerBlock>,
+    won_heights: &HashSet<u64>,
+) {
+    for block in blocks {
+        block.is_won_by_wallet = won_heights.contains(&block.height);
+    }
+}
+
+fn pow_algorithm(header: &BlockHeader) -> BlockPowAlgorithm {
+    header
+        .pow
+        .as_ref()
+        .map(|pow| BlockPowAlgorithm::from(pow.pow_algo))
+        .unwrap_or(BlockPowAlgorithm::Unknown(u64::MAX))
+}
@@ -0,0 +1,379 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+use serde::Serialize;
+use tari_common::configuration::Network;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::CpuMinerConnection;
+use crate::events_emitter::EventsEmitter;
+use crate::network_utils::{get_best_block_from_block_scan, get_block_info_from_block_scan};
+use crate::node::node_adapter::NodeAdapterService;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::chain_tip_verifier";
+
+// Compare hashes a few blocks below the lowest tip so sources racing on the newest block are not reported as a fork
+const TIP_CONFIRMATION_DEPTH: u64 = 5;
+// Sources further behind the highest tip are still syncing and are left out of the hash comparison
+const MAX_TIP_HEIGHT_LAG: u64 = 30;
+const MIN_AGREEING_SOURCES: usize = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub enum ChainTipSource {
+    LocalNode,
+    RemoteNode(String),
+    BlockExplorer,
+}
+
+impl std::fmt::Display for ChainTipSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LocalNode => write!(f, "Local node"),
+            Self::RemoteNode(address) => write!(f, "Remote node ({address})"),
+            Self::BlockExplorer => write!(f, "Block explorer"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ChainTipSample {
+    pub source: ChainTipSource,
+    pub tip_height: u64,
+    pub tip_hash: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ChainTipVerification {
+    pub mining_source: Option<ChainTipSource>,
+    pub reference_height: u64,
+    pub majority_hash: Option<String>,
+    pub samples: Vec<ChainTipSample>,
+    pub lagging_sources: Vec<ChainTipSource>,
+    pub disagreeing_sources: Vec<ChainTipSource>,
+    pub is_minority_chain: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MinorityChainMiningStoppedPayload {
+    pub cpu_mining_stopped: bool,
+    pub gpu_mining_stopped: bool,
+}
+
+/// Stops the miners that submit their blocks through the node once it is on a minority chain,
+/// blocks found there would be orphaned. Pool miners do not use the node and keep running.
+/// Starting them again is refused for as long as the node stays on the minority chain.
+pub async fn stop_mining_on_minority_chain(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+
+    let mut cpu_mining_stopped = false;
+    let is_cpu_mining_through_node = matches!(
+        state.cpu_miner_config.read().await.node_connection,
+        CpuMinerConnection::BuiltInProxy
+    );
+    let mut cpu_miner = state.cpu_miner.write().await;
+    if is_cpu_mining_through_node && cpu_miner.is_running().await {
+        match cpu_miner.stop().await {
+            Ok(()) => cpu_mining_stopped = true,
+            Err(e) => error!(target: LOG_TARGET, "Could not stop CPU miner on minority chain: {e}"),
+        }
+    }
+    drop(cpu_miner);
+
+    let mut gpu_mining_stopped = false;
+    let gpu_miner = state.gpu_miner.read().await;
+    if gpu_miner.is_running().await {
+        match gpu_miner.stop().await {
+            Ok(()) => gpu_mining_stopped = true,
+            Err(e) => error!(target: LOG_TARGET, "Could not stop GPU miner on minority chain: {e}"),
+        }
+    }
+    drop(gpu_miner);
+
+    if cpu_mining_stopped || gpu_mining_stopped {
+        warn!(target: LOG_TARGET, "Node is on a minority chain, stopped mining (cpu: {cpu_mining_stopped}, gpu: {gpu_mining_stopped})");
+        EventsEmitter::emit_minority_chain_mining_stopped(MinorityChainMiningStoppedPayload {
+            cpu_mining_stopped,
+            gpu_mining_stopped,
+        })
+        .await;
+    }
+}
+
+pub(crate) fn to_connection_address(grpc_address: &str) -> String {
+    if grpc_address.starts_with("http") {
+        grpc_address.to_string()
+    } else {
+        format!("http://{grpc_address}")
+    }
+}
+
+pub(crate) struct ChainTipVerifier {
+    mining_source: ChainTipSource,
+    node_sources: Vec<(ChainTipSource, NodeAdapterService)>,
+}
+
+impl ChainTipVerifier {
+    pub fn new(mining_source: ChainTipSource) -> Self {
+        Self {
+            mining_source,
+            node_sources: vec![],
+        }
+    }
+
+    pub fn add_node_source(&mut self, source: ChainTipSource, service: NodeAdapterService) {
+        if self
+            .node_sources
+            .iter()
+            .any(|(existing, _)| *existing == source)
+        {
+            return;
+        }
+        self.node_sources.push((source, service));
+    }
+
+    pub async fn verify(&self) -> Result<ChainTipVerification, anyhow::Error> {
+        let network = Network::get_current_or_user_setting_or_default();
+
+        let mut tips: Vec<(ChainTipSource, u64, Option<String>)> = vec![];
+        for (source, service) in &self.node_sources {
+            match service.get_tip_info().await {
+                Ok((height, hash)) => tips.push((source.clone(), height, Some(hash))),
+                Err(e) => warn!(target: LOG_TARGET, "Could not get chain tip from {source}: {e}"),
+            }
+        }
+        match get_best_block_from_block_scan(network).await {
+            Ok(height) => tips.push((ChainTipSource::BlockExplorer, height, None)),
+            Err(e) => warn!(target: LOG_TARGET, "Could not get chain tip from block explorer: {e}"),
+        }
+
+        if !tips
+            .iter()
+            .any(|(source, _, _)| *source == self.mining_source)
+        {
+            return Err(anyhow!(
+                "{} did not report its chain tip",
+                self.mining_source
+            ));
+        }
+
+        let highest_tip = tips.iter().map(|(_, height, _)| *height).max().unwrap_or(0);
+        let reference_height = tips
+            .iter()
+            .map(|(_, height, _)| *height)
+            .filter(|height| height + MAX_TIP_HEIGHT_LAG >= highest_tip)
+            .min()
+            .unwrap_or(0)
+            .saturating_sub(TIP_CONFIRMATION_DEPTH);
+
+        let mut samples = Vec::with_capacity(tips.len());
+        for (source, tip_height, tip_hash) in tips {
+            let reference_hash = if tip_height + MAX_TIP_HEIGHT_LAG < highest_tip {
+                None
+            } else {
+                self.get_hash_at_height(&source, network, reference_height)
+                    .await
+                    .inspect_err(|e| {
+                        warn!(target: LOG_TARGET, "Could not get block {reference_height} from {source}: {e}")
+                    })
+                    .ok()
+            };
+            samples.push(ChainTipSample {
+                source,
+                tip_height,
+                tip_hash,
+                reference_hash,
+            });
+        }
+
+        let verification =
+            evaluate_chain_tips(self.mining_source.clone(), reference_height, samples);
+        if verification.is_minority_chain {
+            warn!(target: LOG_TARGET, "{} is on a minority chain at height {reference_height} | disagreeing sources: {:?}", self.mining_source, verification.disagreeing_sources);
+        } else {
+            info!(target: LOG_TARGET, "Chain tip verified at height {reference_height} against {} sources", verification.samples.len());
+        }
+
+        Ok(verification)
+    }
+
+    async fn get_hash_at_height(
+        &self,
+        source: &ChainTipSource,
+        network: Network,
+        height: u64,
+    ) -> Result<String, anyhow::Error> {
+        if *source == ChainTipSource::BlockExplorer {
+            let (_, hash) = get_block_info_from_block_scan(network, &height).await?;
+            return Ok(hash);
+        }
+
+        let (_, service) = self
+            .node_sources
+            .iter()
+            .find(|(existing, _)| existing == source)
+            .ok_or_else(|| anyhow!("Unknown chain tip source: {source}"))?;
+        service
+            .get_historical_blocks(vec![height])
+            .await?
+            .into_iter()
+            .find(|(block_height, _)| *block_height == height)
+            .map(|(_, hash)| hash)
+            .ok_or_else(|| anyhow!("Block {height} not found"))
+    }
+}
+
+pub(crate) fn evaluate_chain_tips(
+    mining_source: ChainTipSource,
+    reference_height: u64,
+    samples: Vec<ChainTipSample>,
+) -> ChainTipVerification {
+    let highest_tip = samples.iter().map(|s| s.tip_height).max().unwrap_or(0);
+    let lagging_sources = samples
+        .iter()
+        .filter(|s| s.tip_height + MAX_TIP_HEIGHT_LAG < highest_tip)
+        .map(|s| s.source.clone())
+        .collect();
+
+    let mut hash_votes: HashMap<&str, usize> = HashMap::new();
+    for hash in samples.iter().filter_map(|s| s.reference_hash.as_deref()) {
+        *hash_votes.entry(hash).or_default() += 1;
+    }
+    let most_votes = hash_votes.values().copied().max().unwrap_or(0);
+    let leading_hashes: Vec<&str> = hash_votes
+        .iter()
+        .filter(|(_, votes)| **votes == most_votes)
+        .map(|(hash, _)| *hash)
+        .collect();
+    // A tie between two chains is not enough to tell which one is the minority
+    let majority_hash = match leading_hashes.as_slice() {
+        [hash] if most_votes >= MIN_AGREEING_SOURCES => Some(hash.to_string()),
+        _ => None,
+    };
+
+    let disagreeing_sources: Vec<ChainTipSource> = match &majority_hash {
+        Some(majority_hash) => samples
+            .iter()
+            .filter(|s| {
+                s.reference_hash
+                    .as_ref()
+                    .is_some_and(|hash| hash != majority_hash)
+            })
+            .map(|s| s.source.clone())
+            .collect(),
+        None => vec![],
+    };
+    let is_minority_chain = disagreeing_sources.contains(&mining_source);
+
+    ChainTipVerification {
+        mining_source: Some(mining_source),
+        reference_height,
+        majority_hash,
+        samples,
+        lagging_sources,
+        disagreeing_sources,
+        is_minority_chain,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn sample(
+        source: ChainTipSource,
+        tip_height: u64,
+        reference_hash: Option<&str>,
+    ) -> ChainTipSample {
+        ChainTipSample {
+            source,
+            tip_height,
+            tip_hash: None,
+            reference_hash: reference_hash.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_mining_node_on_minority_chain() {
+        let remote = ChainTipSource::RemoteNode("https://grpc.tari.com:443".to_string());
+        let verification = evaluate_chain_tips(
+            ChainTipSource::LocalNode,
+            95,
+            vec![
+                sample(ChainTipSource::LocalNode, 100, Some("aa")),
+                sample(remote.clone(), 101, Some("bb")),
+                sample(ChainTipSource::BlockExplorer, 100, Some("bb")),
+            ],
+        );
+
+        assert!(verification.is_minority_chain);
+        assert_eq!(verification.majority_hash.as_deref(), Some("bb"));
+        assert_eq!(
+            verification.disagreeing_sources,
+            vec![ChainTipSource::LocalNode]
+        );
+    }
+
+    #[test]
+    fn test_tie_is_not_reported_as_minority() {
+        let verification = evaluate_chain_tips(
+            ChainTipSource::LocalNode,
+            95,
+            vec![
+                sample(ChainTipSource::LocalNode, 100, Some("aa")),
+                sample(ChainTipSource::BlockExplorer, 100, Some("bb")),
+            ],
+        );
+
+        assert!(!verification.is_minority_chain);
+        assert!(verification.majority_hash.is_none());
+        assert!(verification.disagreeing_sources.is_empty());
+    }
+
+    #[test]
+    fn test_lagging_source_is_not_compared() {
+        let remote = ChainTipSource::RemoteNode("https://grpc.tari.com:443".to_string());
+        let verification = evaluate_chain_tips(
+            remote.clone(),
+            995,
+            vec![
+                sample(ChainTipSource::LocalNode, 500, None),
+                sample(remote, 1000, Some("aa")),
+                sample(ChainTipSource::BlockExplorer, 1000, Some("aa")),
+            ],
+        );
+
+        assert!(!verification.is_minority_chain);
+        assert_eq!(
+            verification.lagging_sources,
+            vec![ChainTipSource::LocalNode]
+        );
+        assert!(verification.disagreeing_sources.is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use log::{info, warn};
+use serde::Serialize;
+use tokio::{
+    fs,
+    io::{AsyncReadExt, AsyncWriteExt},
+};
+
+use crate::{
+    events_emitter::EventsEmitter,
+    utils::file_utils::{get_available_space, get_dir_size},
+};
+
+const LOG_TARGET: &str = "tari::universe::node_database_relocation";
+const COPY_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+// LMDB grows while syncing, leave headroom on the target disk after the move
+const FREE_SPACE_MARGIN_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeDatabaseMoveProgressPayload {
+    pub copied_bytes: u64,
+    pub total_bytes: u64,
+    pub progress: f64,
+}
+
+pub(crate) struct NodeDatabaseRelocation {
+    from: PathBuf,
+    to: PathBuf,
+    total_bytes: u64,
+}
+
+impl NodeDatabaseRelocation {
+    /// Validates the target before anything is stopped, so a bad path never interrupts the node
+    pub fn prepare(from: PathBuf, to: PathBuf) -> Result<Self, anyhow::Error> {
+        if to.starts_with(&from) || from.starts_with(&to) {
+            return Err(anyhow!(
+                "New node database location can not be inside the current one: {}",
+                to.display()
+            ));
+        }
+        if to.exists() && std::fs::read_dir(&to)?.next().is_some() {
+            return Err(anyhow!(
+                "New node database location is not empty: {}",
+                to.display()
+            ));
+        }
+
+        let total_bytes = if from.exists() {
+            get_dir_size(&from)?
+        } else {
+            0
+        };
+        let available_bytes = get_available_space(&to)?;
+        let required_bytes = total_bytes + FREE_SPACE_MARGIN_BYTES;
+        if available_bytes < required_bytes {
+            return Err(anyhow!(
+                "Not enough free space at {}: {} bytes required, {} bytes available",
+                to.display(),
+                required_bytes,
+                available_bytes
+            ));
+        }
+
+        Ok(Self {
+            from,
+            to,
+            total_bytes,
+        })
+    }
+
+    /// Must only be called while the local node is stopped
+    pub async fn run(&self) -> Result<(), anyhow::Error> {
+        info!(target: LOG_TARGET, "Moving node database from {:?} to {:?}", self.from, self.to);
+        if !self.from.exists() {
+            info!(target: LOG_TARGET, "No node database at {:?}, nothing to move", self.from);
+            return Ok(());
+        }
+        if let Some(parent) = self.to.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        // An empty target dir left from a previous attempt would make the rename fail
+        if self.to.exists() {
+            fs::remove_dir(&self.to).await?;
+        }
+
+        if fs::rename(&self.from, &self.to).await.is_ok() {
+            info!(target: LOG_TARGET, "Node database moved with rename");
+            self.emit_progress(self.total_bytes).await;
+            return Ok(());
+        }
+
+        // Different filesystem, copy into a staging dir first so a failed copy never looks like a database
+        let staging_dir = self.to.with_file_name("db.moving");
+        if staging_dir.exists() {
+            fs::remove_dir_all(&staging_dir).await?;
+        }
+        if let Err(e) = self.copy_dir(&staging_dir).await {
+            warn!(target: LOG_TARGET, "Failed to copy node database: {e:?}");
+            let _unused = fs::remove_dir_all(&staging_dir).await.inspect_err(|e| {
+                warn!(target: LOG_TARGET, "Failed to remove partial node database copy: {e:?}");
+            });
+            return Err(e);
+        }
+        fs::rename(&staging_dir, &self.to).await?;
+
+        if let Err(e) = fs::remove_dir_all(&self.from).await {
+            warn!(target: LOG_TARGET, "Node database copied but the old one could not be removed: {e:?}");
+        }
+        info!(target: LOG_TARGET, "Node database moved to {:?}", self.to);
+        Ok(())
+    }
+
+    async fn copy_dir(&self, staging_dir: &Path) -> Result<(), anyhow::Error> {
+        let mut copied_bytes = 0;
+        let mut last_reported_percentage = 0;
+        let mut pending = vec![(self.from.clone(), staging_dir.to_path_buf())];
+        let mut buffer = vec![0; COPY_BUFFER_SIZE];
+
+        while let Some((source_dir, target_dir)) = pending.pop() {
+            fs::create_dir_all(&target_dir).await?;
+            let mut entries = fs::read_dir(&source_dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let target_path = target_dir.join(entry.file_name());
+                if entry.file_type().await?.is_dir() {
+                    pending.push((entry.path(), target_path));
+                    continue;
+                }
+
+                let mut source = fs::File::open(entry.path()).await?;
+                let mut target = fs::File::create(&target_path).await?;
+                loop {
+                    let read = source.read(&mut buffer).await?;
+                    if read == 0 {
+                        break;
+                    }
+                    target.write_all(&buffer[..read]).await?;
+                    copied_bytes += read as u64;
+
+                    let percentage = copied_bytes * 100 / self.total_bytes.max(1);
+                    if percentage > last_reported_percentage {
+                        last_reported_percentage = percentage;
+                        self.emit_progress(copied_bytes).await;
+                    }
+                }
+                target.sync_all().await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn emit_progress(&self, copied_bytes: u64) {
+        #[allow(clippy::cast_precision_loss)]
+        let progress = if self.total_bytes == 0 {
+            1.0
+        } else {
+            copied_bytes as f64 / self.total_bytes as f64
+        };
+        EventsEmitter::emit_node_database_move_progress(NodeDatabaseMoveProgressPayload {
+            copied_bytes,
+            total_bytes: self.total_bytes,
+            progress,
+        })
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use rand::random;
+
+    fn test_dir() -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("node_database_relocation_{}", random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn prepare_error(from: PathBuf, to: PathBuf) -> String {
+        NodeDatabaseRelocation::prepare(from, to)
+            .err()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn rejects_target_inside_source() {
+        let dir = test_dir();
+        let from = dir.join("db");
+        std::fs::create_dir_all(&from).unwrap();
+
+        let error = prepare_error(from.clone(), from.join("nested"));
+        assert!(error.contains("can not be inside the current one"));
+        let error = prepare_error(from.join("nested"), from);
+        assert!(error.contains("can not be inside the current one"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_target_that_is_not_empty() {
+        let dir = test_dir();
+        let from = dir.join("db");
+        let to = dir.join("new_db");
+        std::fs::create_dir_all(&from).unwrap();
+        std::fs::create_dir_all(&to).unwrap();
+        std::fs::write(to.join("data.mdb"), "existing").unwrap();
+
+        let error = prepare_error(from, to);
+        assert!(error.contains("is not empty"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_same_path() {
+        let dir = test_dir();
+        let from = dir.join("db");
+        std::fs::create_dir_all(&from).unwrap();
+
+        let error = prepare_error(from.clone(), from);
+        assert!(error.contains("can not be inside the current one"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -24,7 +24,9 @@ use crate::ab_test_selector::ABTestSelector;
 use crate::node::node_adapter::{
     BaseNodeStatus, NodeAdapter, NodeAdapterService, NodeStatusMonitor,
 };
+use crate::node::node_config_overrides::{merge_config_overrides, NodeConfigOverrides};
 use crate::node::node_manager::NodeType;
+use crate::node::utils::get_node_db_dir;
 use crate::port_allocator::PortAllocator;
 use crate::process_adapter::{ProcessAdapter, ProcessInstance, ProcessStartupSpec};
 use crate::utils::file_utils::convert_to_string;
@@ -79,6 +81,8 @@ pub(crate) struct LocalNodeAdapter {
     required_initial_peers: u32,
     pub(crate) ab_test_group: ABTestSelector,
     pub(crate) http_api_port: u16,
+    pub(crate) node_data_dir: Option<PathBuf>,
+    pub(crate) config_overrides: NodeConfigOverrides,
 }
 
 impl LocalNodeAdapter {
@@ -97,6 +101,8 @@ impl LocalNodeAdapter {
             tor_control_port: None,
             ab_test_group: ABTestSelector::GroupA,
             http_api_port,
+            node_data_dir: None,
+            config_overrides: NodeConfigOverrides::default(),
         }
     }
 
@@ -161,6 +167,14 @@ impl NodeAdapter for LocalNodeAdapter {
     fn set_ab_group(&mut self, ab_test_group: ABTestSelector) {
         self.ab_test_group = ab_test_group;
     }
+
+    fn set_node_data_dir(&mut self, node_data_dir: Option<PathBuf>) {
+        self.node_data_dir = node_data_dir;
+    }
+
+    fn set_config_overrides(&mut self, config_overrides: NodeConfigOverrides) {
+        self.config_overrides = config_overrides;
+    }
 }
 
 impl ProcessAdapter for LocalNodeAdapter {
@@ -259,7 +273,7 @@ impl ProcessAdapter for LocalNodeAdapter {
                 self.required_initial_peers
             ),
             "-p".to_string(),
-            "base_node.grpc_server_allow_methods=\"list_connected_peers, get_blocks\"".to_string(),
+            "base_node.grpc_server_allow_methods=\"list_connected_peers, get_blocks, list_headers, get_header_by_hash, get_mempool_stats, get_mempool_transactions, get_mempool_fee_per_gram_stats, transaction_state, submit_transaction\"".to_string(),
             "-p".to_string(),
             "base_node.p2p.allow_test_addresses=true".to_string(),
             "-p".to_string(),
@@ -270,6 +284,18 @@ impl ProcessAdapter for LocalNodeAdapter {
                 self.http_api_port
             ),
         ];
+        if let Some(node_data_dir) = &self.node_data_dir {
+            let db_dir = get_node_db_dir(&data_dir, Some(node_data_dir));
+            if let Some(parent) = db_dir.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            info!(target: LOG_TARGET, "Using custom node database location: {db_dir:?}");
+            args.push("-p".to_string());
+            args.push(format!(
+                "base_node.lmdb_path={}",
+                convert_to_string(db_dir)?
+            ));
+        }
         if self.use_pruned_mode {
             args.push("-p".to_string());
             args.push("base_node.storage.pruning_horizon=100".to_string());
@@ -371,6 +397,16 @@ impl ProcessAdapter for LocalNodeAdapter {
             args.push("base_node.p2p.dht.minimize_connections=false".to_string());
         }
 
+        // User overrides go last so they win over the defaults above
+        let (config_overrides, warnings) = self.config_overrides.resolve();
+        for warning in warnings {
+            warn!(target: LOG_TARGET, "{warning}");
+        }
+        if !config_overrides.is_empty() {
+            info!(target: LOG_TARGET, "Applying node config overrides: {config_overrides:?}");
+            merge_config_overrides(&mut args, &config_overrides);
+        }
+
         #[cfg(target_os = "windows")]
         add_firewall_rule("minotari_node.exe".to_string(), binary_version_path.clone())?;
 
@@ -0,0 +1,229 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use anyhow::anyhow;
+use minotari_node_grpc_client::grpc::{Signature, Transaction, TransactionLocation};
+use serde::Serialize;
+
+// Consensus transaction weights in grams
+const KERNEL_WEIGHT: u64 = 10;
+const INPUT_WEIGHT: u64 = 8;
+const OUTPUT_WEIGHT: u64 = 53;
+const SCRIPT_BYTES_PER_GRAM: u64 = 16;
+const PUBLIC_NONCE_LENGTH: usize = 32;
+const SIGNATURE_LENGTH: usize = 32;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MempoolStats {
+    pub unconfirmed_txs: u64,
+    pub reorg_txs: u64,
+    pub unconfirmed_weight: u64,
+    pub fee_distribution: Option<MempoolFeeDistribution>,
+}
+
+/// Fee per gram percentiles of the transactions in the mempool
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MempoolFeeDistribution {
+    pub min: u64,
+    pub p25: u64,
+    pub median: u64,
+    pub p75: u64,
+    pub max: u64,
+}
+
+/// Fee per gram of the transactions the mempool would put into one upcoming block, the first
+/// entry being the next block
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MempoolFeePerGramStat {
+    pub min_fee_per_gram: u64,
+    pub avg_fee_per_gram: u64,
+    pub max_fee_per_gram: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum TransactionLocationStatus {
+    InMempool,
+    Mined,
+    NotStored,
+    Unknown,
+}
+
+impl From<TransactionLocation> for TransactionLocationStatus {
+    fn from(location: TransactionLocation) -> Self {
+        match location {
+            TransactionLocation::Mempool => Self::InMempool,
+            TransactionLocation::Mined => Self::Mined,
+            TransactionLocation::NotStored => Self::NotStored,
+            TransactionLocation::Unknown => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MempoolTransactionStatus {
+    pub location: TransactionLocationStatus,
+    pub is_in_mempool: bool,
+    pub mempool_size: u64,
+}
+
+/// Fee per gram of every transaction, one entry per transaction
+pub fn transaction_fees_per_gram(transactions: &[Transaction]) -> Vec<u64> {
+    transactions
+        .iter()
+        .filter_map(|transaction| {
+            let body = transaction.body.as_ref()?;
+            let fee: u64 = body.kernels.iter().map(|kernel| kernel.fee).sum();
+            let script_bytes: u64 = body
+                .outputs
+                .iter()
+                .map(|output| {
+                    ((output.script.len() + output.covenant.len()) as u64)
+                        .div_ceil(SCRIPT_BYTES_PER_GRAM)
+                })
+                .sum();
+            let weight = body.kernels.len() as u64 * KERNEL_WEIGHT
+                + body.inputs.len() as u64 * INPUT_WEIGHT
+                + body.outputs.len() as u64 * OUTPUT_WEIGHT
+                + script_bytes;
+            fee.checked_div(weight)
+        })
+        .collect()
+}
+
+pub fn fee_distribution(mut fees_per_gram: Vec<u64>) -> Option<MempoolFeeDistribution> {
+    if fees_per_gram.is_empty() {
+        return None;
+    }
+    fees_per_gram.sort_unstable();
+    let percentile = |percent: usize| {
+        fees_per_gram
+            .get((fees_per_gram.len() - 1) * percent / 100)
+            .copied()
+            .unwrap_or_default()
+    };
+
+    Some(MempoolFeeDistribution {
+        min: percentile(0),
+        p25: percentile(25),
+        median: percentile(50),
+        p75: percentile(75),
+        max: percentile(100),
+    })
+}
+
+/// The node looks transactions up by the full kernel excess signature, the public nonce followed
+/// by the signature
+pub fn kernel_excess_sig(excess_sig: &[u8]) -> Result<Signature, anyhow::Error> {
+    if excess_sig.len() != PUBLIC_NONCE_LENGTH + SIGNATURE_LENGTH {
+        return Err(anyhow!(
+            "Excess signature must be {} bytes, the public nonce followed by the signature",
+            PUBLIC_NONCE_LENGTH + SIGNATURE_LENGTH
+        ));
+    }
+    let (public_nonce, signature) = excess_sig.split_at(PUBLIC_NONCE_LENGTH);
+    Ok(Signature {
+        public_nonce: public_nonce.to_vec(),
+        signature: signature.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use minotari_node_grpc_client::grpc::{
+        AggregateBody, TransactionInput, TransactionKernel, TransactionOutput,
+    };
+
+    fn transaction(fee: u64, inputs: usize, outputs: usize) -> Transaction {
+        Transaction {
+            body: Some(AggregateBody {
+                inputs: vec![TransactionInput::default(); inputs],
+                outputs: vec![TransactionOutput::default(); outputs],
+                kernels: vec![TransactionKernel {
+                    fee,
+                    ..Default::default()
+                }],
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fees_are_divided_by_transaction_weight() {
+        // 10 + 8 + 2 * 53 = 124 grams
+        let small = transaction(1240, 1, 2);
+        // 10 + 4 * 8 + 2 * 53 = 148 grams
+        let large = transaction(1480, 4, 2);
+        assert_eq!(transaction_fees_per_gram(&[small, large]), vec![10, 10]);
+    }
+
+    #[test]
+    fn script_bytes_add_to_the_weight() {
+        let mut transaction = transaction(1240 + 20, 1, 2);
+        if let Some(output) = transaction
+            .body
+            .as_mut()
+            .and_then(|body| body.outputs.first_mut())
+        {
+            output.script = vec![0; 17];
+        }
+        // 17 script bytes round up to 2 grams
+        assert_eq!(transaction_fees_per_gram(&[transaction]), vec![10]);
+    }
+
+    #[test]
+    fn fee_distribution_uses_percentiles_of_fee_per_gram() {
+        assert_eq!(fee_distribution(vec![]), None);
+        assert_eq!(
+            fee_distribution(vec![50, 10, 40, 20, 30]),
+            Some(MempoolFeeDistribution {
+                min: 10,
+                p25: 20,
+                median: 30,
+                p75: 40,
+                max: 50,
+            })
+        );
+        assert_eq!(
+            fee_distribution(vec![7]),
+            Some(MempoolFeeDistribution {
+                min: 7,
+                p25: 7,
+                median: 7,
+                p75: 7,
+                max: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn splits_excess_sig_into_nonce_and_signature() {
+        let mut excess_sig = vec![1u8; PUBLIC_NONCE_LENGTH];
+        excess_sig.extend(vec![2u8; SIGNATURE_LENGTH]);
+
+        let signature = kernel_excess_sig(&excess_sig).unwrap();
+        assert_eq!(signature.public_nonce, vec![1u8; PUBLIC_NONCE_LENGTH]);
+        assert_eq!(signature.signature, vec![2u8; SIGNATURE_LENGTH]);
+        assert!(kernel_excess_sig(&[2u8; SIGNATURE_LENGTH]).is_err());
+    }
+}
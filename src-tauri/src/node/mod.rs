@@ -20,8 +20,16 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod block_explorer;
+pub mod chain_tip_verifier;
+pub mod database_relocation;
 pub mod local_node_adapter;
+pub mod mempool;
+pub mod network_stats_history;
 pub mod node_adapter;
+pub mod node_config_overrides;
 pub mod node_manager;
+pub mod orphan_chain_recovery;
 pub mod remote_node_adapter;
+pub mod snapshot_bootstrap;
 pub mod utils;
@@ -0,0 +1,333 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+use crate::node::block_explorer::{BlockPowAlgorithm, ExplorerBlock};
+use crate::node::node_adapter::BaseNodeStatus;
+use crate::solo_mining_estimator::ALGORITHM_BLOCK_TIME_SECS;
+
+// About three days of blocks across all algorithms
+pub const MAX_SAMPLES: usize = 2160;
+// Roughly an hour of blocks, enough to flatten single block swings in the estimated hashrate
+pub const HASHRATE_AVERAGE_WINDOW: usize = 30;
+
+static INSTANCE: LazyLock<RwLock<NetworkStatsHistory>> =
+    LazyLock::new(|| RwLock::new(NetworkStatsHistory::new()));
+
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct NetworkStatsSample {
+    pub block_height: u64,
+    pub timestamp: u64,
+    pub sha3x_hashrate: u64,
+    pub tari_randomx_hashrate: u64,
+    pub monero_randomx_hashrate: u64,
+    pub sha3x_difficulty: Option<u64>,
+    pub tari_randomx_difficulty: Option<u64>,
+    pub monero_randomx_difficulty: Option<u64>,
+}
+
+impl NetworkStatsSample {
+    /// Difficulties are taken from the newest block of each algorithm in `recent_blocks`
+    pub(crate) fn new(node_status: &BaseNodeStatus, recent_blocks: &[ExplorerBlock]) -> Self {
+        let latest_difficulty = |pow_algo: BlockPowAlgorithm| {
+            recent_blocks
+                .iter()
+                .find(|block| block.pow_algo == pow_algo)
+                .map(|block| block.difficulty)
+        };
+
+        Self {
+            block_height: node_status.block_height,
+            timestamp: node_status.block_time,
+            sha3x_hashrate: node_status.sha_network_hashrate,
+            tari_randomx_hashrate: node_status.tari_randomx_network_hashrate,
+            monero_randomx_hashrate: node_status.monero_randomx_network_hashrate,
+            sha3x_difficulty: latest_difficulty(BlockPowAlgorithm::Sha3x),
+            tari_randomx_difficulty: latest_difficulty(BlockPowAlgorithm::RandomXT),
+            monero_randomx_difficulty: latest_difficulty(BlockPowAlgorithm::RandomXM),
+        }
+    }
+
+    /// Rebuilds samples from block headers, oldest first. Headers carry no hashrate, so it is
+    /// derived from the newest difficulty of each algorithm over its target block time.
+    pub(crate) fn from_headers(blocks: &[ExplorerBlock]) -> Vec<Self> {
+        let mut blocks: Vec<&ExplorerBlock> = blocks.iter().collect();
+        blocks.sort_by_key(|block| block.height);
+
+        let mut latest = Self::default();
+        blocks
+            .into_iter()
+            .map(|block| {
+                let difficulty = Some(block.difficulty);
+                let hashrate = block.difficulty / ALGORITHM_BLOCK_TIME_SECS;
+                match block.pow_algo {
+                    BlockPowAlgorithm::Sha3x => {
+                        latest.sha3x_difficulty = difficulty;
+                        latest.sha3x_hashrate = hashrate;
+                    }
+                    BlockPowAlgorithm::RandomXT => {
+                        latest.tari_randomx_difficulty = difficulty;
+                        latest.tari_randomx_hashrate = hashrate;
+                    }
+                    BlockPowAlgorithm::RandomXM => {
+                        latest.monero_randomx_difficulty = difficulty;
+                        latest.monero_randomx_hashrate = hashrate;
+                    }
+                    BlockPowAlgorithm::Unknown(_) => {}
+                }
+                latest.block_height = block.height;
+                latest.timestamp = block.timestamp;
+                latest
+            })
+            .collect()
+    }
+
+    fn hashrate(&self, pow_algo: BlockPowAlgorithm) -> u64 {
+        match pow_algo {
+            BlockPowAlgorithm::Sha3x => self.sha3x_hashrate,
+            BlockPowAlgorithm::RandomXT => self.tari_randomx_hashrate,
+            BlockPowAlgorithm::RandomXM => self.monero_randomx_hashrate,
+            BlockPowAlgorithm::Unknown(_) => 0,
+        }
+    }
+}
+
+/// Rolling per block history of the network hashrates and difficulties reported by the node
+pub struct NetworkStatsHistory {
+    samples: VecDeque<NetworkStatsSample>,
+}
+
+impl NetworkStatsHistory {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(MAX_SAMPLES),
+        }
+    }
+
+    pub fn current() -> &'static RwLock<NetworkStatsHistory> {
+        &INSTANCE
+    }
+
+    pub fn latest_height(&self) -> Option<u64> {
+        self.samples.back().map(|sample| sample.block_height)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Prepends samples older than anything recorded so far, `samples` are expected oldest first
+    pub fn backfill(&mut self, samples: Vec<NetworkStatsSample>) {
+        for sample in samples.into_iter().rev() {
+            if self.samples.len() >= MAX_SAMPLES {
+                break;
+            }
+            if self
+                .samples
+                .front()
+                .is_some_and(|first| first.block_height <= sample.block_height)
+            {
+                continue;
+            }
+            self.samples.push_front(sample);
+        }
+    }
+
+    pub fn record(&mut self, sample: NetworkStatsSample) {
+        // A reorg can report heights we already have, drop everything from that height on
+        while self
+            .samples
+            .back()
+            .is_some_and(|last| last.block_height >= sample.block_height)
+        {
+            self.samples.pop_back();
+        }
+        if self.samples.len() >= MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn samples_from(&self, from_height: Option<u64>) -> Vec<NetworkStatsSample> {
+        self.samples
+            .iter()
+            .filter(|sample| {
+                from_height.is_none_or(|from_height| sample.block_height >= from_height)
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Mean of the last `window` non zero hashrates reported for the algorithm
+    pub fn average_hashrate(&self, pow_algo: BlockPowAlgorithm, window: usize) -> Option<u64> {
+        let hashrates: Vec<u64> = self
+            .samples
+            .iter()
+            .rev()
+            .map(|sample| sample.hashrate(pow_algo))
+            .filter(|hashrate| *hashrate > 0)
+            .take(window)
+            .collect();
+        if hashrates.is_empty() {
+            return None;
+        }
+
+        let sum: u128 = hashrates.iter().map(|hashrate| u128::from(*hashrate)).sum();
+        #[allow(clippy::cast_possible_truncation)]
+        Some((sum / hashrates.len() as u128) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    fn sample(block_height: u64, sha3x_hashrate: u64) -> NetworkStatsSample {
+        NetworkStatsSample {
+            block_height,
+            sha3x_hashrate,
+            ..Default::default()
+        }
+    }
+
+    fn block(height: u64, pow_algo: BlockPowAlgorithm, difficulty: u64) -> ExplorerBlock {
+        ExplorerBlock {
+            height,
+            hash: format!("{height:064x}"),
+            timestamp: height * ALGORITHM_BLOCK_TIME_SECS,
+            pow_algo,
+            difficulty,
+            reward: MicroMinotari(0),
+            num_transactions: 1,
+            is_won_by_wallet: false,
+        }
+    }
+
+    fn heights(history: &NetworkStatsHistory) -> Vec<u64> {
+        history
+            .samples_from(None)
+            .iter()
+            .map(|sample| sample.block_height)
+            .collect()
+    }
+
+    #[test]
+    fn reorg_replaces_samples_from_the_reported_height() {
+        let mut history = NetworkStatsHistory::new();
+        for height in 1..=5 {
+            history.record(sample(height, 100));
+        }
+        history.record(sample(4, 200));
+
+        assert_eq!(heights(&history), vec![1, 2, 3, 4]);
+        assert_eq!(
+            history
+                .samples_from(Some(4))
+                .first()
+                .unwrap()
+                .sha3x_hashrate,
+            200
+        );
+    }
+
+    #[test]
+    fn backfill_only_prepends_older_samples() {
+        let mut history = NetworkStatsHistory::new();
+        history.record(sample(10, 100));
+        history.backfill((7..=11).map(|height| sample(height, 50)).collect());
+
+        assert_eq!(heights(&history), vec![7, 8, 9, 10]);
+        assert_eq!(history.latest_height(), Some(10));
+        assert_eq!(
+            history
+                .samples_from(Some(10))
+                .first()
+                .unwrap()
+                .sha3x_hashrate,
+            100
+        );
+    }
+
+    #[test]
+    fn backfill_keeps_the_newest_samples_when_full() {
+        let mut history = NetworkStatsHistory::new();
+        let newest = MAX_SAMPLES as u64 + 10;
+        history.record(sample(newest, 100));
+        history.backfill((1..newest).map(|height| sample(height, 50)).collect());
+
+        assert_eq!(history.samples_from(None).len(), MAX_SAMPLES);
+        assert_eq!(history.samples_from(None).first().unwrap().block_height, 11);
+    }
+
+    #[test]
+    fn samples_from_headers_carry_each_algorithms_latest_difficulty() {
+        let sha3x_difficulty = 1_000 * ALGORITHM_BLOCK_TIME_SECS;
+        let randomx_difficulty = 10 * ALGORITHM_BLOCK_TIME_SECS;
+        // Tip first, as the node lists them
+        let blocks = vec![
+            block(3, BlockPowAlgorithm::Sha3x, 2 * sha3x_difficulty),
+            block(2, BlockPowAlgorithm::RandomXM, randomx_difficulty),
+            block(1, BlockPowAlgorithm::Sha3x, sha3x_difficulty),
+        ];
+
+        let samples = NetworkStatsSample::from_headers(&blocks);
+
+        assert_eq!(samples.len(), 3);
+        let first = samples.first().unwrap();
+        assert_eq!(first.block_height, 1);
+        assert_eq!(first.sha3x_hashrate, 1_000);
+        assert_eq!(first.monero_randomx_difficulty, None);
+        let last = samples.last().unwrap();
+        assert_eq!(last.block_height, 3);
+        assert_eq!(last.sha3x_hashrate, 2_000);
+        assert_eq!(last.monero_randomx_hashrate, 10);
+        assert_eq!(last.monero_randomx_difficulty, Some(randomx_difficulty));
+    }
+
+    #[test]
+    fn average_hashrate_skips_samples_without_hashrate() {
+        let mut history = NetworkStatsHistory::new();
+        history.record(sample(1, 100));
+        history.record(sample(2, 0));
+        history.record(sample(3, 300));
+
+        assert_eq!(
+            history.average_hashrate(BlockPowAlgorithm::Sha3x, HASHRATE_AVERAGE_WINDOW),
+            Some(200)
+        );
+        assert_eq!(
+            history.average_hashrate(BlockPowAlgorithm::Sha3x, 1),
+            Some(300)
+        );
+        assert_eq!(
+            history.average_hashrate(BlockPowAlgorithm::RandomXT, HASHRATE_AVERAGE_WINDOW),
+            None
+        );
+    }
+}
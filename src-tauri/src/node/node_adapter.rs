@@ -21,13 +21,23 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::ab_test_selector::ABTestSelector;
+use crate::events::BackgroundNodeSyncUpdatePayload;
+use crate::node::block_explorer::{ExplorerBlock, ExplorerBlockDetails};
+use crate::node::mempool::{
+    fee_distribution, kernel_excess_sig, transaction_fees_per_gram, MempoolFeePerGramStat,
+    MempoolStats, MempoolTransactionStatus, TransactionLocationStatus,
+};
+use crate::node::node_config_overrides::NodeConfigOverrides;
 use crate::node::node_manager::NodeType;
-use crate::node::utils::SyncProgressInfo;
+use crate::node::utils::{NodeSyncRateEstimator, SyncProgressInfo};
 use crate::process_adapter::{HandleUnhealthyResult, HealthStatus, StatusMonitor};
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
 use minotari_node_grpc_client::grpc::{
-    BlockHeader, Empty, GetBlocksRequest, GetNetworkStateRequest,
+    BlockHeader, Empty, GetBlocksRequest, GetHeaderByHashRequest, GetMempoolFeePerGramStatsRequest,
+    GetMempoolTransactionsRequest, GetNetworkStateRequest, ListHeadersRequest, Sorting,
+    SubmitTransactionRequest, SubmitTransactionResult, Transaction, TransactionLocation,
+    TransactionStateRequest,
 };
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime};
@@ -38,7 +48,6 @@ use chrono::{NaiveDateTime, TimeZone, Utc};
 use log::{error, info, warn};
 use minotari_node_grpc_client::BaseNodeGrpcClient;
 use serde::Serialize;
-use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
@@ -65,6 +74,8 @@ pub trait NodeAdapter {
     fn use_tor(&mut self, use_tor: bool);
     fn set_tor_control_port(&mut self, tor_control_port: Option<u16>);
     fn set_ab_group(&mut self, ab_group: ABTestSelector);
+    fn set_node_data_dir(&mut self, node_data_dir: Option<PathBuf>);
+    fn set_config_overrides(&mut self, config_overrides: NodeConfigOverrides);
 }
 
 #[derive(Debug, Clone)]
@@ -143,6 +154,174 @@ impl NodeAdapterService {
         Ok(blocks)
     }
 
+    /// Returns the newest `count` blocks, tip first
+    pub async fn get_recent_blocks(&self, count: u64) -> Result<Vec<ExplorerBlock>, Error> {
+        let (tip_height, _) = self.get_tip_info().await?;
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+
+        let mut res = client
+            .list_headers(ListHeadersRequest {
+                from_height: tip_height,
+                num_headers: count,
+                sorting: Sorting::Desc as i32,
+            })
+            .await?
+            .into_inner();
+
+        let mut blocks = Vec::new();
+        while let Some(header_response) = res.message().await? {
+            if let Some(block) = ExplorerBlock::from_header_response(header_response) {
+                blocks.push(block);
+            }
+        }
+        Ok(blocks)
+    }
+
+    pub async fn get_block_details(&self, height: u64) -> Result<ExplorerBlockDetails, Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+
+        let historical_block = client
+            .get_blocks(GetBlocksRequest {
+                heights: vec![height],
+            })
+            .await?
+            .into_inner()
+            .message()
+            .await?
+            .ok_or_else(|| anyhow!("Block {height} not found"))?;
+        let hash = historical_block
+            .block
+            .as_ref()
+            .and_then(|block| block.header.as_ref())
+            .map(|header| header.hash.clone())
+            .ok_or_else(|| anyhow!("Block {height} is missing its header"))?;
+        let header_response = client
+            .get_header_by_hash(GetHeaderByHashRequest { hash })
+            .await?
+            .into_inner();
+
+        ExplorerBlockDetails::from_historical_block(historical_block, header_response)
+            .ok_or_else(|| anyhow!("Block {height} is missing its header"))
+    }
+
+    async fn get_mempool_transactions(&self) -> Result<Vec<Transaction>, Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let mut res = client
+            .get_mempool_transactions(GetMempoolTransactionsRequest {})
+            .await?
+            .into_inner();
+
+        let mut transactions = Vec::new();
+        while let Some(response) = res.message().await? {
+            if let Some(transaction) = response.transaction {
+                transactions.push(transaction);
+            }
+        }
+        Ok(transactions)
+    }
+
+    /// Adds a signed transaction to the node's mempool, which propagates it to its peers
+    pub async fn submit_transaction(&self, transaction: Transaction) -> Result<(), Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let res = client
+            .submit_transaction(SubmitTransactionRequest {
+                transaction: Some(transaction),
+            })
+            .await?
+            .into_inner();
+
+        match SubmitTransactionResult::try_from(res.result) {
+            Ok(SubmitTransactionResult::Accepted) => Ok(()),
+            Ok(SubmitTransactionResult::AlreadyMined) => {
+                Err(anyhow!("Transaction is already mined"))
+            }
+            Ok(result) => Err(anyhow!(
+                "Node did not accept the transaction: {}",
+                result.as_str_name()
+            )),
+            Err(_) => Err(anyhow!(
+                "Node returned an unknown submit result: {}",
+                res.result
+            )),
+        }
+    }
+
+    pub async fn get_mempool_stats(&self) -> Result<MempoolStats, Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let stats = client.get_mempool_stats(Empty {}).await?.into_inner();
+        let transactions = self.get_mempool_transactions().await?;
+
+        Ok(MempoolStats {
+            unconfirmed_txs: stats.unconfirmed_txs,
+            reorg_txs: stats.reorg_txs,
+            unconfirmed_weight: stats.unconfirmed_weight,
+            fee_distribution: fee_distribution(transaction_fees_per_gram(&transactions)),
+        })
+    }
+
+    pub async fn get_mempool_fee_per_gram_stats(
+        &self,
+        block_count: u64,
+    ) -> Result<Vec<MempoolFeePerGramStat>, Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let res = client
+            .get_mempool_fee_per_gram_stats(GetMempoolFeePerGramStatsRequest { count: block_count })
+            .await?
+            .into_inner();
+
+        let mut items = res.items;
+        items.sort_by_key(|item| item.order);
+        Ok(items
+            .into_iter()
+            .map(|item| MempoolFeePerGramStat {
+                min_fee_per_gram: item.min_fee_per_gram,
+                avg_fee_per_gram: item.avg_fee_per_gram,
+                max_fee_per_gram: item.max_fee_per_gram,
+            })
+            .collect())
+    }
+
+    pub async fn get_mempool_transaction_status(
+        &self,
+        excess_sig: &[u8],
+    ) -> Result<MempoolTransactionStatus, Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let res = client
+            .transaction_state(TransactionStateRequest {
+                excess_sig: Some(kernel_excess_sig(excess_sig)?),
+            })
+            .await?
+            .into_inner();
+        let location = TransactionLocation::try_from(res.result).map_or(
+            TransactionLocationStatus::Unknown,
+            TransactionLocationStatus::from,
+        );
+        let mempool_size = client
+            .get_mempool_stats(Empty {})
+            .await?
+            .into_inner()
+            .unconfirmed_txs;
+
+        Ok(MempoolTransactionStatus {
+            location,
+            is_in_mempool: location == TransactionLocationStatus::InMempool,
+            mempool_size,
+        })
+    }
+
+    pub async fn get_tip_info(&self) -> Result<(u64, String), Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let res = client.get_tip_info(Empty {}).await?.into_inner();
+        let metadata = res
+            .metadata
+            .ok_or_else(|| anyhow!("Tip info is missing chain metadata"))?;
+
+        Ok((
+            metadata.best_block_height,
+            hex::encode(metadata.best_block_hash),
+        ))
+    }
+
     pub async fn get_identity(&self) -> Result<NodeIdentity, Error> {
         let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
         let id = client.identify(Empty {}).await?;
@@ -157,7 +336,7 @@ impl NodeAdapterService {
 
     pub async fn wait_synced(
         &self,
-        progress_params_tx: &watch::Sender<HashMap<String, String>>,
+        sync_update_tx: &watch::Sender<Option<BackgroundNodeSyncUpdatePayload>>,
         progress_percentage_tx: &watch::Sender<f64>,
         shutdown_signal: ShutdownSignal,
     ) -> Result<u64, NodeStatusMonitorError> {
@@ -165,6 +344,7 @@ impl NodeAdapterService {
             .await
             .map_err(|_e| NodeStatusMonitorError::NodeNotStarted)?;
 
+        let mut rate_estimator = NodeSyncRateEstimator::default();
         loop {
             if shutdown_signal.is_triggered() {
                 return Ok(0);
@@ -181,11 +361,14 @@ impl NodeAdapterService {
 
             let tip_res = tip.into_inner();
             let sync_progress = sync_progress.into_inner();
-            let sync_info =
-                SyncProgressInfo::from_sync_progress(&sync_progress, self.required_sync_peers);
+            let sync_info = SyncProgressInfo::from_sync_progress(
+                &sync_progress,
+                self.required_sync_peers,
+                &mut rate_estimator,
+            );
 
             progress_percentage_tx.send(sync_info.percentage).ok();
-            progress_params_tx.send(sync_info.progress_params).ok();
+            sync_update_tx.send(Some(sync_info.update)).ok();
 
             if tip_res.initial_sync_achieved
                 && tip_res.metadata.clone().is_some_and(|metadata| {
@@ -238,6 +421,41 @@ impl NodeAdapterService {
         Ok(connected_peers)
     }
 
+    pub async fn get_connected_peers_info(&self) -> Result<Vec<ConnectedPeerInfo>, anyhow::Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let peers_list = client
+            .list_connected_peers(Empty {})
+            .await
+            .map_err(|e| anyhow::anyhow!("Error list_connected_peers: {}", e))?
+            .into_inner()
+            .connected_peers;
+
+        let connected_peers = peers_list
+            .into_iter()
+            .map(|peer| {
+                // Addresses are ordered by the node, the first one is the one it connected with
+                let primary_address = peer.addresses.first();
+                ConnectedPeerInfo {
+                    node_id: peer.node_id.to_hex(),
+                    public_key: peer.public_key.to_hex(),
+                    addresses: peer
+                        .addresses
+                        .iter()
+                        .map(|address| address.address.to_hex())
+                        .collect(),
+                    last_seen: primary_address.map(|address| address.last_seen.clone()),
+                    latency_ms: primary_address
+                        .and_then(|address| address.avg_latency.as_ref())
+                        .map(|avg_latency| avg_latency.latency),
+                    user_agent: peer.user_agent,
+                    is_banned: peer.banned_until > 0,
+                }
+            })
+            .collect();
+
+        Ok(connected_peers)
+    }
+
     pub async fn check_if_is_orphan_chain(&self) -> Result<bool, anyhow::Error> {
         let network = Network::get_current_or_user_setting_or_default();
         let block_scan_tip = get_best_block_from_block_scan(network).await?;
@@ -412,6 +630,17 @@ pub struct NodeIdentity {
     pub public_addresses: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectedPeerInfo {
+    pub node_id: String,
+    pub public_key: String,
+    pub addresses: Vec<String>,
+    pub last_seen: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub user_agent: String,
+    pub is_banned: bool,
+}
+
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
 pub struct MigrationProgress {
     pub current_block: u64,
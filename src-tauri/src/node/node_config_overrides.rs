@@ -0,0 +1,285 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+
+/// Keys the app sets itself, overriding them would break the connection between the app and the node
+const APP_MANAGED_KEYS: &[&str] = &[
+    "base_node.grpc_enabled",
+    "base_node.grpc_address",
+    "base_node.grpc_server_allow_methods",
+    "base_node.report_grpc_error",
+    "base_node.http_wallet_query_service.port",
+    "base_node.lmdb_path",
+    "base_node.p2p.transport.type",
+    "base_node.p2p.transport.tor.control_address",
+];
+
+const KNOWN_BASE_NODE_KEYS: &[&str] = &[
+    "base_node.storage.pruning_horizon",
+    "base_node.storage.pruning_interval",
+    "base_node.p2p.public_addresses",
+    "base_node.p2p.transport.tcp.listener_address",
+    "base_node.p2p.auxiliary_tcp_listener_address",
+    "base_node.p2p.allow_test_addresses",
+    "base_node.p2p.max_concurrent_inbound_tasks",
+    "base_node.p2p.max_concurrent_outbound_tasks",
+    "base_node.p2p.dht.num_neighbouring_nodes",
+    "base_node.p2p.dht.num_random_nodes",
+    "base_node.p2p.dht.minimize_connections",
+    "base_node.p2p.dht.network_discovery.min_desired_peers",
+    "base_node.p2p.transport.tor.proxy_bypass_for_outbound_tcp",
+    "base_node.state_machine.initial_sync_peer_count",
+    "base_node.state_machine.blockchain_sync_config.initial_max_sync_latency",
+    "base_node.state_machine.blockchain_sync_config.max_latency_increase",
+    "base_node.mempool.unconfirmed_pool.storage_capacity",
+    "base_node.mempool.reorg_pool.expiry_height",
+];
+
+/// Known keys that live under the network section, e.g. `mainnet.p2p.seeds.peer_seeds`
+const KNOWN_NETWORK_KEYS: &[&str] = &["p2p.seeds.peer_seeds", "p2p.seeds.dns_seeds"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct NodeConfigOverrides {
+    pub pruning_horizon: Option<u64>,
+    pub min_desired_peers: Option<u32>,
+    /// Closest peers the node keeps connections to, together with `random_peers` this is the
+    /// node's peer connection limit
+    pub neighbouring_peers: Option<u32>,
+    pub random_peers: Option<u32>,
+    pub seed_peers: Vec<String>,
+    pub listener_address: Option<String>,
+    pub public_addresses: Vec<String>,
+    /// Freeform `key=value` pairs passed to the node as `-p` arguments
+    pub extra: BTreeMap<String, String>,
+}
+
+impl NodeConfigOverrides {
+    /// Returns the overrides that will be passed to the node together with warnings for the ones that will not
+    /// or that are not known to the app
+    pub fn resolve(&self) -> (Vec<(String, String)>, Vec<String>) {
+        let network_key = Network::get_current_or_user_setting_or_default()
+            .as_key_str()
+            .to_string();
+        let mut overrides: Vec<(String, String)> = Vec::new();
+        let mut warnings = Vec::new();
+
+        if let Some(pruning_horizon) = self.pruning_horizon {
+            overrides.push((
+                "base_node.storage.pruning_horizon".to_string(),
+                pruning_horizon.to_string(),
+            ));
+        }
+        if let Some(min_desired_peers) = self.min_desired_peers {
+            overrides.push((
+                "base_node.p2p.dht.network_discovery.min_desired_peers".to_string(),
+                min_desired_peers.to_string(),
+            ));
+        }
+        if let Some(neighbouring_peers) = self.neighbouring_peers {
+            overrides.push((
+                "base_node.p2p.dht.num_neighbouring_nodes".to_string(),
+                neighbouring_peers.to_string(),
+            ));
+        }
+        if let Some(random_peers) = self.random_peers {
+            overrides.push((
+                "base_node.p2p.dht.num_random_nodes".to_string(),
+                random_peers.to_string(),
+            ));
+        }
+        if !self.seed_peers.is_empty() {
+            for seed_peer in &self.seed_peers {
+                if !seed_peer.contains("::") {
+                    warnings.push(format!(
+                        "Seed peer '{seed_peer}' is not in the <public_key>::<address> format"
+                    ));
+                }
+            }
+            overrides.push((
+                format!("{network_key}.p2p.seeds.peer_seeds"),
+                self.seed_peers.join(","),
+            ));
+        }
+        if let Some(listener_address) = &self.listener_address {
+            overrides.push((
+                "base_node.p2p.transport.tcp.listener_address".to_string(),
+                listener_address.clone(),
+            ));
+        }
+        if !self.public_addresses.is_empty() {
+            overrides.push((
+                "base_node.p2p.public_addresses".to_string(),
+                self.public_addresses.join(","),
+            ));
+        }
+
+        for (key, value) in &self.extra {
+            let key = key.trim();
+            if key.is_empty() || key.contains(['=', ' ', '\n']) || value.contains('\n') {
+                warnings.push(format!("Ignoring malformed node config override '{key}'"));
+                continue;
+            }
+            if APP_MANAGED_KEYS.contains(&key) {
+                warnings.push(format!(
+                    "Ignoring node config override '{key}', it is managed by the app"
+                ));
+                continue;
+            }
+            let is_known =
+                KNOWN_BASE_NODE_KEYS.contains(&key)
+                    || key.strip_prefix(&format!("{network_key}.")).is_some_and(
+                        |network_scoped_key| KNOWN_NETWORK_KEYS.contains(&network_scoped_key),
+                    );
+            if !is_known {
+                warnings.push(format!(
+                    "Unknown node config key '{key}', it will be passed to the node as is"
+                ));
+            }
+            overrides.push((key.to_string(), value.clone()));
+        }
+
+        (overrides, warnings)
+    }
+}
+
+/// Replaces the value of `-p key=value` arguments already present in `args`, appending the rest
+pub fn merge_config_overrides(args: &mut Vec<String>, overrides: &[(String, String)]) {
+    for (key, value) in overrides {
+        let key_prefix = format!("{key}=");
+        let argument = format!("{key}={value}");
+        match args.iter_mut().find(|arg| arg.starts_with(&key_prefix)) {
+            Some(existing) => *existing = argument,
+            None => {
+                args.push("-p".to_string());
+                args.push(argument);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn network_key() -> String {
+        Network::get_current_or_user_setting_or_default()
+            .as_key_str()
+            .to_string()
+    }
+
+    #[test]
+    fn resolve_maps_typed_overrides_to_node_keys() {
+        let config_overrides = NodeConfigOverrides {
+            pruning_horizon: Some(1000),
+            neighbouring_peers: Some(12),
+            random_peers: Some(6),
+            seed_peers: vec!["aa::/ip4/1.2.3.4/tcp/18189".to_string()],
+            ..Default::default()
+        };
+        let (overrides, warnings) = config_overrides.resolve();
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            overrides,
+            vec![
+                (
+                    "base_node.storage.pruning_horizon".to_string(),
+                    "1000".to_string()
+                ),
+                (
+                    "base_node.p2p.dht.num_neighbouring_nodes".to_string(),
+                    "12".to_string()
+                ),
+                (
+                    "base_node.p2p.dht.num_random_nodes".to_string(),
+                    "6".to_string()
+                ),
+                (
+                    format!("{}.p2p.seeds.peer_seeds", network_key()),
+                    "aa::/ip4/1.2.3.4/tcp/18189".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_warns_about_unknown_malformed_and_app_managed_keys() {
+        let config_overrides = NodeConfigOverrides {
+            seed_peers: vec!["/ip4/1.2.3.4/tcp/18189".to_string()],
+            extra: BTreeMap::from([
+                ("base_node.grpc_address".to_string(), "x".to_string()),
+                ("bad key".to_string(), "1".to_string()),
+                ("base_node.custom".to_string(), "1".to_string()),
+                (
+                    format!("{}.p2p.seeds.dns_seeds", network_key()),
+                    "seeds.tari.com".to_string(),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let (overrides, warnings) = config_overrides.resolve();
+
+        assert_eq!(warnings.len(), 4);
+        let keys: Vec<&str> = overrides.iter().map(|(key, _)| key.as_str()).collect();
+        assert!(keys.contains(&"base_node.custom"));
+        assert!(keys.contains(&format!("{}.p2p.seeds.dns_seeds", network_key()).as_str()));
+        assert!(!keys.contains(&"base_node.grpc_address"));
+        assert!(!keys.contains(&"bad key"));
+    }
+
+    #[test]
+    fn merge_config_overrides_replaces_existing_arguments() {
+        let mut args = vec![
+            "-p".to_string(),
+            "base_node.storage.pruning_horizon=100".to_string(),
+        ];
+        merge_config_overrides(
+            &mut args,
+            &[
+                (
+                    "base_node.storage.pruning_horizon".to_string(),
+                    "1000".to_string(),
+                ),
+                (
+                    "base_node.p2p.dht.num_random_nodes".to_string(),
+                    "6".to_string(),
+                ),
+            ],
+        );
+
+        assert_eq!(
+            args,
+            vec![
+                "-p",
+                "base_node.storage.pruning_horizon=1000",
+                "-p",
+                "base_node.p2p.dht.num_random_nodes=6",
+            ]
+        );
+    }
+}
@@ -27,6 +27,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use log::{error, info, warn};
+use minotari_node_grpc_client::grpc::Transaction;
 use serde::{Deserialize, Serialize};
 use tari_common::configuration::Network;
 use tari_crypto::ristretto::RistrettoPublicKey;
@@ -39,9 +40,18 @@ use tokio_util::task::TaskTracker;
 
 use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
+use crate::events::BackgroundNodeSyncUpdatePayload;
+use crate::node::block_explorer::{ExplorerBlock, ExplorerBlockDetails};
+use crate::node::chain_tip_verifier::{
+    to_connection_address, ChainTipSource, ChainTipVerification, ChainTipVerifier,
+};
+use crate::node::mempool::{MempoolFeePerGramStat, MempoolStats, MempoolTransactionStatus};
 use crate::node::node_adapter::{
-    NodeAdapter, NodeAdapterService, NodeIdentity, NodeStatusMonitorError, ReadinessStatus,
+    ConnectedPeerInfo, NodeAdapter, NodeAdapterService, NodeIdentity, NodeStatusMonitorError,
+    ReadinessStatus,
 };
+use crate::node::snapshot_bootstrap::SnapshotSource;
+use crate::node::utils::get_node_db_dir;
 use crate::process_adapter::ProcessAdapter;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
@@ -49,6 +59,7 @@ use crate::process_watcher::ProcessWatcherStats;
 use crate::progress_trackers::progress_stepper::IncrementalProgressTracker;
 use crate::setup::setup_manager::SetupManager;
 use crate::tasks_tracker::TasksTrackers;
+use crate::utils::sync_progress::SyncRateEstimator;
 use crate::{BaseNodeStatus, LocalNodeAdapter, RemoteNodeAdapter};
 
 const LOG_TARGET: &str = "tari::universe::minotari_node_manager";
@@ -95,6 +106,8 @@ pub struct NodeManager {
     remote_node_watch_rx: watch::Receiver<BaseNodeStatus>,
     local_node_db_cleared: Arc<AtomicBool>,
     orphan_chain_detected: Arc<AtomicBool>,
+    minority_chain_detected: Arc<AtomicBool>,
+    pending_snapshot: Arc<RwLock<Option<SnapshotSource>>>,
 }
 
 impl NodeManager {
@@ -136,6 +149,8 @@ impl NodeManager {
             remote_node_watch_rx,
             local_node_db_cleared: Arc::new(AtomicBool::new(false)),
             orphan_chain_detected: Arc::new(AtomicBool::new(false)),
+            minority_chain_detected: Arc::new(AtomicBool::new(false)),
+            pending_snapshot: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -223,8 +238,14 @@ impl NodeManager {
         if let Some(node_watcher) = node_watcher.as_mut() {
             node_watcher.adapter.use_tor(use_tor);
             node_watcher.adapter.set_tor_control_port(tor_control_port);
-            let ab_group = *ConfigCore::content().await.ab_group();
-            node_watcher.adapter.set_ab_group(ab_group);
+            let config_core = ConfigCore::content().await;
+            node_watcher.adapter.set_ab_group(*config_core.ab_group());
+            node_watcher
+                .adapter
+                .set_node_data_dir(config_core.node_data_dir().clone());
+            node_watcher
+                .adapter
+                .set_config_overrides(config_core.node_config_overrides().clone());
 
             if let Some(remote_grpc_address) = remote_grpc_address {
                 node_watcher.adapter.set_grpc_address(remote_grpc_address)?;
@@ -256,13 +277,13 @@ impl NodeManager {
             .get_task_tracker()
             .await
             .spawn(async move {
-                let (progress_params_tx, _) = watch::channel(HashMap::<String, String>::new());
+                let (sync_update_tx, _) = watch::channel(None);
                 let (progress_percentage_tx, _) = watch::channel(0f64);
 
                 monitor_local_node_sync_and_switch(
                     node_manager,
                     node_type,
-                    progress_params_tx,
+                    sync_update_tx,
                     progress_percentage_tx,
                     shutdown_signal,
                 )
@@ -274,7 +295,7 @@ impl NodeManager {
 
     pub async fn wait_synced(
         &self,
-        progress_params_tx: &watch::Sender<HashMap<String, String>>,
+        sync_update_tx: &watch::Sender<Option<BackgroundNodeSyncUpdatePayload>>,
         progress_percentage_tx: &watch::Sender<f64>,
     ) -> Result<(), anyhow::Error> {
         let shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
@@ -282,7 +303,7 @@ impl NodeManager {
             let current_service = self.get_current_service().await?;
             match current_service
                 .wait_synced(
-                    progress_params_tx,
+                    sync_update_tx,
                     progress_percentage_tx,
                     shutdown_signal.clone(),
                 )
@@ -312,6 +333,7 @@ impl NodeManager {
                         .spawn(async move {
                             let mut shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
                             let mut migration_completed = false;
+                            let mut rate_estimator = SyncRateEstimator::default();
 
                             while !migration_completed {
                                 tokio::select! {
@@ -333,6 +355,12 @@ impl NodeManager {
                                                         params.insert("total_blocks".to_string(), progress.total_blocks.to_string());
                                                         params.insert("current_db_version".to_string(), progress.current_db_version.to_string());
                                                         params.insert("target_db_version".to_string(), progress.target_db_version.to_string());
+                                                        if let Some(rate) = rate_estimator.update(progress.current_block, progress.total_blocks) {
+                                                            params.insert("blocks_per_second".to_string(), format!("{:.1}", rate.items_per_second));
+                                                            if let Some(eta_seconds) = rate.eta_seconds {
+                                                                params.insert("eta_seconds".to_string(), eta_seconds.to_string());
+                                                            }
+                                                        }
 
                                                         if let Some(tracker) = &migration_tracker {
                                                             tracker.send_update(params, progress.progress_percentage / 100.0).await;
@@ -381,11 +409,26 @@ impl NodeManager {
                 .join(Network::get_current().to_string().to_lowercase()),
         )
         .await?;
+        if let Some(node_data_dir) = ConfigCore::content().await.node_data_dir() {
+            let db_dir = get_node_db_dir(base_path, Some(node_data_dir));
+            if db_dir.exists() {
+                fs::remove_dir_all(db_dir).await?;
+            }
+        }
         self.local_node_db_cleared
             .store(true, std::sync::atomic::Ordering::SeqCst);
         Ok(())
     }
 
+    /// Queues a snapshot to be applied the next time the local node is started
+    pub async fn set_pending_snapshot(&self, source: SnapshotSource) {
+        *self.pending_snapshot.write().await = Some(source);
+    }
+
+    pub async fn take_pending_snapshot(&self) -> Option<SnapshotSource> {
+        self.pending_snapshot.write().await.take()
+    }
+
     pub async fn get_node_type(&self) -> NodeType {
         let node_type = self.node_type.read().await;
         node_type.clone()
@@ -459,11 +502,110 @@ impl NodeManager {
             .load(std::sync::atomic::Ordering::SeqCst)
     }
 
+    pub async fn verify_chain_tip(&self) -> Result<Option<ChainTipVerification>, anyhow::Error> {
+        let base_node_status = *self.base_node_watch_tx.borrow();
+        if !base_node_status.is_synced {
+            info!(target: LOG_TARGET, "Node is not synced, skipping chain tip verification");
+            return Ok(None);
+        }
+
+        let mining_source = if self.is_local_current().await {
+            ChainTipSource::LocalNode
+        } else {
+            ChainTipSource::RemoteNode(self.get_grpc_address().await?)
+        };
+        let mut verifier = ChainTipVerifier::new(mining_source.clone());
+        verifier.add_node_source(mining_source, self.get_current_service().await?);
+
+        if self.is_local().await && self.local_node_watch_rx.borrow().is_synced {
+            let local_service = {
+                let local_node_watcher = self.local_node_watcher.read().await;
+                local_node_watcher
+                    .as_ref()
+                    .and_then(|watcher| watcher.adapter.get_service())
+            };
+            if let Some(local_service) = local_service {
+                verifier.add_node_source(ChainTipSource::LocalNode, local_service);
+            }
+        }
+
+        let config_core = ConfigCore::content().await;
+        let remote_addresses = std::iter::once(config_core.remote_base_node_address())
+            .chain(config_core.chain_tip_verification_nodes().iter());
+        for remote_address in remote_addresses {
+            let connection_address = to_connection_address(remote_address);
+            verifier.add_node_source(
+                ChainTipSource::RemoteNode(connection_address.clone()),
+                NodeAdapterService::new(connection_address, 1),
+            );
+        }
+
+        let verification = verifier.verify().await?;
+        self.minority_chain_detected.store(
+            verification.is_minority_chain,
+            std::sync::atomic::Ordering::SeqCst,
+        );
+        Ok(Some(verification))
+    }
+
+    pub fn is_on_minority_chain(&self) -> bool {
+        self.minority_chain_detected
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
     pub async fn list_connected_peers(&self) -> Result<Vec<String>, anyhow::Error> {
         let current_service = self.get_current_service().await?;
         current_service.list_connected_peers().await
     }
 
+    pub async fn get_connected_peers_info(&self) -> Result<Vec<ConnectedPeerInfo>, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service.get_connected_peers_info().await
+    }
+
+    pub async fn get_recent_blocks(&self, count: u64) -> Result<Vec<ExplorerBlock>, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service.get_recent_blocks(count).await
+    }
+
+    pub async fn get_block_details(
+        &self,
+        height: u64,
+    ) -> Result<ExplorerBlockDetails, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service.get_block_details(height).await
+    }
+
+    pub async fn submit_transaction(&self, transaction: Transaction) -> Result<(), anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service.submit_transaction(transaction).await
+    }
+
+    pub async fn get_mempool_stats(&self) -> Result<MempoolStats, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service.get_mempool_stats().await
+    }
+
+    pub async fn get_mempool_fee_per_gram_stats(
+        &self,
+        block_count: u64,
+    ) -> Result<Vec<MempoolFeePerGramStat>, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service
+            .get_mempool_fee_per_gram_stats(block_count)
+            .await
+    }
+
+    pub async fn get_mempool_transaction_status(
+        &self,
+        excess_sig: &[u8],
+    ) -> Result<MempoolTransactionStatus, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service
+            .get_mempool_transaction_status(excess_sig)
+            .await
+    }
+
     // Self Checks
     pub async fn is_local(&self) -> bool {
         let node_type = self.get_node_type().await;
@@ -698,7 +840,7 @@ where
 async fn monitor_local_node_sync_and_switch(
     node_manager: NodeManager,
     node_type: Arc<RwLock<NodeType>>,
-    progress_params_tx: watch::Sender<HashMap<String, String>>,
+    sync_update_tx: watch::Sender<Option<BackgroundNodeSyncUpdatePayload>>,
     progress_percentage_tx: watch::Sender<f64>,
     mut shutdown_signal: ShutdownSignal,
 ) {
@@ -714,7 +856,7 @@ async fn monitor_local_node_sync_and_switch(
                     local_node_watcher.as_ref().and_then(|watcher| watcher.adapter.get_service())
                 } {
                     match local_node_service
-                        .wait_synced(&progress_params_tx, &progress_percentage_tx, shutdown_signal.clone())
+                        .wait_synced(&sync_update_tx, &progress_percentage_tx, shutdown_signal.clone())
                         .await
                     {
                         Ok(synced_height) => {
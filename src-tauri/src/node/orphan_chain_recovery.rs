@@ -0,0 +1,418 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tokio::sync::{watch, RwLock};
+
+use crate::configs::config_core::ConfigCore;
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::node::snapshot_bootstrap::SnapshotSource;
+use crate::setup::setup_manager::{SetupManager, SetupPhase};
+use crate::tasks_tracker::TasksTrackers;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::orphan_chain_recovery";
+
+// Restarting the node with a fresh peer database normally gets it back within minutes
+const RECONNECT_SYNC_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+const RESYNC_TIMEOUT: Duration = Duration::from_secs(12 * 60 * 60);
+
+static INSTANCE: LazyLock<RwLock<OrphanChainRecoveryState>> =
+    LazyLock::new(|| RwLock::new(OrphanChainRecoveryState::default()));
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OrphanChainRecoveryConfig {
+    pub enabled: bool,
+    /// How long the node has to stay on an orphan chain before recovery starts
+    pub grace_period_secs: u64,
+    /// Snapshot used to resync when reconnecting to fresh peers is not enough, the node
+    /// resyncs from scratch when unset
+    pub snapshot_manifest_url: Option<String>,
+}
+
+impl Default for OrphanChainRecoveryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            grace_period_secs: 10 * 60,
+            snapshot_manifest_url: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "step")]
+pub enum OrphanChainRecoveryStep {
+    Started,
+    ReconnectingPeers,
+    ResyncingFromSnapshot,
+    /// Reconnecting did not help and no snapshot is configured, the node database is only
+    /// deleted once the user confirms with `confirm_orphan_chain_resync`
+    AwaitingResyncConfirmation,
+    ResyncingFromScratch,
+    Recovered,
+    Failed {
+        error: String,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct OrphanChainRecoveryState {
+    detected_since: Option<Instant>,
+    in_progress: bool,
+    awaiting_resync_confirmation: bool,
+}
+
+enum RecoveryOutcome {
+    Recovered,
+    ResyncConfirmationRequired,
+}
+
+impl OrphanChainRecoveryState {
+    pub fn current() -> &'static RwLock<OrphanChainRecoveryState> {
+        &INSTANCE
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.in_progress
+    }
+
+    /// Records the result of an orphan chain check, returns true once the node has been on an
+    /// orphan chain for longer than `grace_period` and recovery should start
+    pub fn should_start_recovery(
+        &mut self,
+        is_orphan: bool,
+        grace_period: Duration,
+        now: Instant,
+    ) -> bool {
+        if self.in_progress {
+            return false;
+        }
+        if !is_orphan {
+            self.detected_since = None;
+            return false;
+        }
+
+        let detected_since = *self.detected_since.get_or_insert(now);
+        if now.saturating_duration_since(detected_since) < grace_period {
+            return false;
+        }
+        self.in_progress = true;
+        true
+    }
+
+    fn finish(&mut self) {
+        self.in_progress = false;
+        self.awaiting_resync_confirmation = false;
+        self.detected_since = None;
+    }
+
+    /// Returns whether a from-scratch resync was waiting for the user, recovery stays in
+    /// progress when it was so the periodic check does not start it again meanwhile
+    fn take_resync_confirmation(&mut self) -> bool {
+        std::mem::take(&mut self.awaiting_resync_confirmation)
+    }
+}
+
+/// Tries to get the local node off an orphan chain, first by restarting it with fresh peers and
+/// then by resyncing its database from a snapshot. Solo mining is paused until recovery is done.
+///
+/// The node does not expose rewinding the chain, so resyncing is the only way to roll back.
+/// Without a snapshot the whole chain has to be downloaded again, which only happens after the
+/// user confirms through [`confirm_orphan_chain_resync`].
+pub async fn run_orphan_chain_recovery(app_handle: AppHandle) {
+    info!(target: LOG_TARGET, "Starting orphan chain recovery");
+    EventsEmitter::emit_orphan_chain_recovery_update(OrphanChainRecoveryStep::Started).await;
+
+    let mining_phases = solo_mining_phases().await;
+    SetupManager::get_instance()
+        .shutdown_phases(mining_phases.clone())
+        .await;
+
+    let step = match recover(&app_handle).await {
+        Ok(RecoveryOutcome::Recovered) => recovered_step().await,
+        Ok(RecoveryOutcome::ResyncConfirmationRequired) => {
+            info!(target: LOG_TARGET, "Waiting for the user to confirm resyncing the node from scratch");
+            OrphanChainRecoveryState::current()
+                .write()
+                .await
+                .awaiting_resync_confirmation = true;
+            SetupManager::get_instance()
+                .resume_phases(mining_phases)
+                .await;
+            EventsEmitter::emit_orphan_chain_recovery_update(
+                OrphanChainRecoveryStep::AwaitingResyncConfirmation,
+            )
+            .await;
+            return;
+        }
+        Err(e) => failed_step(e),
+    };
+
+    SetupManager::get_instance()
+        .resume_phases(mining_phases)
+        .await;
+    OrphanChainRecoveryState::current().write().await.finish();
+    EventsEmitter::emit_orphan_chain_recovery_update(step).await;
+}
+
+/// Answers a pending [`OrphanChainRecoveryStep::AwaitingResyncConfirmation`]. When confirmed the
+/// node database is deleted and the chain is synced again, otherwise recovery is dropped until
+/// the node has been on an orphan chain for another grace period.
+pub async fn confirm_orphan_chain_resync(
+    app_handle: AppHandle,
+    confirmed: bool,
+) -> Result<(), anyhow::Error> {
+    if !OrphanChainRecoveryState::current()
+        .write()
+        .await
+        .take_resync_confirmation()
+    {
+        return Err(anyhow::anyhow!(
+            "No orphan chain resync is waiting for confirmation"
+        ));
+    }
+    if !confirmed {
+        info!(target: LOG_TARGET, "Resyncing the node from scratch was declined");
+        OrphanChainRecoveryState::current().write().await.finish();
+        return Ok(());
+    }
+
+    TasksTrackers::current()
+        .common
+        .get_task_tracker()
+        .await
+        .spawn(run_orphan_chain_resync(app_handle));
+    Ok(())
+}
+
+async fn run_orphan_chain_resync(app_handle: AppHandle) {
+    info!(target: LOG_TARGET, "Resyncing the node from scratch");
+    let mining_phases = solo_mining_phases().await;
+    SetupManager::get_instance()
+        .shutdown_phases(mining_phases.clone())
+        .await;
+
+    let step = match resync_from_scratch(&app_handle).await {
+        Ok(()) => recovered_step().await,
+        Err(e) => failed_step(e),
+    };
+
+    SetupManager::get_instance()
+        .resume_phases(mining_phases)
+        .await;
+    OrphanChainRecoveryState::current().write().await.finish();
+    EventsEmitter::emit_orphan_chain_recovery_update(step).await;
+}
+
+async fn recovered_step() -> OrphanChainRecoveryStep {
+    info!(target: LOG_TARGET, "Orphan chain recovery finished");
+    EventsEmitter::emit_stuck_on_orphan_chain(false).await;
+    OrphanChainRecoveryStep::Recovered
+}
+
+fn failed_step(e: anyhow::Error) -> OrphanChainRecoveryStep {
+    error!(target: LOG_TARGET, "Orphan chain recovery failed: {e}");
+    OrphanChainRecoveryStep::Failed {
+        error: e.to_string(),
+    }
+}
+
+async fn recover(app_handle: &AppHandle) -> Result<RecoveryOutcome, anyhow::Error> {
+    let state = app_handle.state::<UniverseAppState>();
+    let node_phases = vec![SetupPhase::Node, SetupPhase::Wallet];
+
+    // The peer database is cleared on every node start
+    EventsEmitter::emit_orphan_chain_recovery_update(OrphanChainRecoveryStep::ReconnectingPeers)
+        .await;
+    SetupManager::get_instance()
+        .restart_phases(node_phases.clone())
+        .await;
+    // Resyncing throws away the whole database, so it only happens once the node is known to
+    // still be on an orphan chain
+    if !wait_synced_and_check(app_handle, RECONNECT_SYNC_TIMEOUT)
+        .await
+        .map_err(|e| {
+            anyhow::anyhow!("Could not verify the chain after reconnecting to fresh peers: {e}")
+        })?
+    {
+        return Ok(RecoveryOutcome::Recovered);
+    }
+    warn!(target: LOG_TARGET, "Node is still on an orphan chain after reconnecting to fresh peers");
+
+    let snapshot_manifest_url = ConfigCore::content()
+        .await
+        .orphan_chain_recovery()
+        .snapshot_manifest_url
+        .clone();
+    let Some(manifest_url) = snapshot_manifest_url else {
+        return Ok(RecoveryOutcome::ResyncConfirmationRequired);
+    };
+
+    EventsEmitter::emit_orphan_chain_recovery_update(
+        OrphanChainRecoveryStep::ResyncingFromSnapshot,
+    )
+    .await;
+    state
+        .node_manager
+        .set_pending_snapshot(SnapshotSource::Download { manifest_url })
+        .await;
+    SetupManager::get_instance()
+        .restart_phases(node_phases)
+        .await;
+
+    ensure_off_orphan_chain(app_handle).await?;
+    Ok(RecoveryOutcome::Recovered)
+}
+
+async fn resync_from_scratch(app_handle: &AppHandle) -> Result<(), anyhow::Error> {
+    let state = app_handle.state::<UniverseAppState>();
+    let node_phases = vec![SetupPhase::Node, SetupPhase::Wallet];
+
+    EventsEmitter::emit_orphan_chain_recovery_update(OrphanChainRecoveryStep::ResyncingFromScratch)
+        .await;
+    let base_path = app_handle.path().app_local_data_dir()?;
+    SetupManager::get_instance()
+        .shutdown_phases(node_phases.clone())
+        .await;
+    let cleaned = state.node_manager.clean_data_folder(&base_path).await;
+    SetupManager::get_instance()
+        .resume_phases(node_phases)
+        .await;
+    cleaned?;
+
+    ensure_off_orphan_chain(app_handle).await
+}
+
+async fn ensure_off_orphan_chain(app_handle: &AppHandle) -> Result<(), anyhow::Error> {
+    if wait_synced_and_check(app_handle, RESYNC_TIMEOUT).await? {
+        return Err(anyhow::anyhow!(
+            "Node is still on an orphan chain after resyncing"
+        ));
+    }
+    Ok(())
+}
+
+/// Waits for the restarted node to sync and returns whether it is still on an orphan chain
+async fn wait_synced_and_check(
+    app_handle: &AppHandle,
+    timeout: Duration,
+) -> Result<bool, anyhow::Error> {
+    let state = app_handle.state::<UniverseAppState>();
+    let (sync_update_tx, _) = watch::channel(None);
+    let (progress_percentage_tx, _) = watch::channel(0.0);
+
+    tokio::time::timeout(
+        timeout,
+        state
+            .node_manager
+            .wait_synced(&sync_update_tx, &progress_percentage_tx),
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("Timed out waiting for the node to sync"))??;
+
+    state.node_manager.check_if_is_orphan_chain().await
+}
+
+async fn solo_mining_phases() -> Vec<SetupPhase> {
+    let config_pools = ConfigPools::content().await;
+    let mut phases = vec![];
+    if !*config_pools.cpu_pool_enabled() {
+        phases.push(SetupPhase::CpuMining);
+    }
+    if !*config_pools.gpu_pool_enabled() {
+        phases.push(SetupPhase::GpuMining);
+    }
+    phases
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const GRACE_PERIOD: Duration = Duration::from_secs(600);
+
+    #[test]
+    fn starts_recovery_only_after_grace_period() {
+        let mut state = OrphanChainRecoveryState::default();
+        let start = Instant::now();
+
+        assert!(!state.should_start_recovery(true, GRACE_PERIOD, start));
+        assert!(!state.should_start_recovery(true, GRACE_PERIOD, start + Duration::from_secs(300)));
+        assert!(state.should_start_recovery(true, GRACE_PERIOD, start + GRACE_PERIOD));
+        assert!(state.is_in_progress());
+    }
+
+    #[test]
+    fn healthy_check_resets_grace_period() {
+        let mut state = OrphanChainRecoveryState::default();
+        let start = Instant::now();
+
+        assert!(!state.should_start_recovery(true, GRACE_PERIOD, start));
+        assert!(!state.should_start_recovery(
+            false,
+            GRACE_PERIOD,
+            start + Duration::from_secs(300)
+        ));
+        assert!(!state.should_start_recovery(true, GRACE_PERIOD, start + GRACE_PERIOD));
+    }
+
+    #[test]
+    fn does_not_start_twice_while_in_progress() {
+        let mut state = OrphanChainRecoveryState::default();
+        let start = Instant::now();
+
+        assert!(state.should_start_recovery(true, Duration::ZERO, start));
+        assert!(!state.should_start_recovery(true, Duration::ZERO, start));
+
+        state.finish();
+        assert!(state.should_start_recovery(true, Duration::ZERO, start));
+    }
+
+    #[test]
+    fn stays_in_progress_while_awaiting_resync_confirmation() {
+        let mut state = OrphanChainRecoveryState::default();
+        let start = Instant::now();
+
+        assert!(state.should_start_recovery(true, Duration::ZERO, start));
+        state.awaiting_resync_confirmation = true;
+        assert!(!state.should_start_recovery(true, Duration::ZERO, start));
+
+        assert!(state.take_resync_confirmation());
+        assert!(!state.take_resync_confirmation());
+        assert!(state.is_in_progress());
+    }
+
+    #[test]
+    fn recovery_is_disabled_by_default() {
+        assert!(!OrphanChainRecoveryConfig::default().enabled);
+    }
+}
@@ -139,6 +139,14 @@ impl NodeAdapter for RemoteNodeAdapter {
         log::info!(target: LOG_TARGET, "RemoteNodeAdapter doesn't use tor_control_port");
     }
 
+    fn set_node_data_dir(&mut self, _node_data_dir: Option<PathBuf>) {
+        log::info!(target: LOG_TARGET, "RemoteNodeAdapter doesn't use node_data_dir");
+    }
+
+    fn set_config_overrides(&mut self, _config_overrides: NodeConfigOverrides) {
+        log::info!(target: LOG_TARGET, "RemoteNodeAdapter doesn't use config_overrides");
+    }
+
     async fn get_connection_details(&self) -> Result<(RistrettoPublicKey, String), anyhow::Error> {
         let node_service = self.get_service();
         if let Some(node_service) = node_service {
@@ -0,0 +1,519 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tari_common::configuration::Network;
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+use tokio::sync::watch;
+
+use crate::download_utils::extract;
+use crate::network_utils::get_block_info_from_block_scan;
+use crate::progress_trackers::progress_stepper::IncrementalProgressTracker;
+use crate::requests::clients::http_client::HttpClient;
+use crate::requests::clients::http_file_client::HttpFileClient;
+
+const LOG_TARGET: &str = "tari::universe::snapshot_bootstrap";
+const LMDB_DATA_FILE: &str = "data.mdb";
+const CHECKSUM_BUFFER_SIZE: usize = 1024 * 1024;
+// Extensions `download_utils::extract` knows how to unpack
+const ARCHIVE_EXTENSIONS: [&str; 3] = ["gz", "tgz", "zip"];
+
+// Share of the bootstrap step each stage accounts for in the progress tracker
+const DOWNLOAD_STAGE_END: f64 = 0.6;
+const VERIFY_STAGE_END: f64 = 0.75;
+const EXTRACT_STAGE_END: f64 = 0.95;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub network: String,
+    pub height: u64,
+    pub block_hash: String,
+    /// Sha256 of the compressed archive
+    pub checksum: String,
+    pub archive_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum SnapshotSource {
+    Download {
+        manifest_url: String,
+    },
+    Import {
+        archive_path: PathBuf,
+        manifest_path: Option<PathBuf>,
+        /// Imports without a manifest skip every check, so they have to be asked for explicitly
+        allow_unverified: bool,
+    },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SnapshotBootstrapResultPayload {
+    pub success: bool,
+    /// False when the snapshot was imported without a manifest to check it against
+    pub verified: bool,
+    pub height: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl SnapshotBootstrapResultPayload {
+    pub fn from_result(result: &Result<Option<SnapshotManifest>, anyhow::Error>) -> Self {
+        match result {
+            Ok(manifest) => Self {
+                success: true,
+                verified: manifest.is_some(),
+                height: manifest.as_ref().map(|manifest| manifest.height),
+                error: None,
+            },
+            Err(e) => Self {
+                success: false,
+                verified: false,
+                height: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BootstrapStage {
+    Download,
+    Verify,
+    Extract,
+    Swap,
+}
+
+impl BootstrapStage {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Download => "download",
+            Self::Verify => "verify",
+            Self::Extract => "extract",
+            Self::Swap => "swap",
+        }
+    }
+}
+
+pub(crate) struct SnapshotBootstrap {
+    source: SnapshotSource,
+    db_dir: PathBuf,
+    staging_dir: PathBuf,
+    progress_tracker: Option<IncrementalProgressTracker>,
+}
+
+impl SnapshotBootstrap {
+    pub fn new(
+        source: SnapshotSource,
+        db_dir: PathBuf,
+        progress_tracker: Option<IncrementalProgressTracker>,
+    ) -> Self {
+        // Staged next to the database so the final swap is a rename on the same filesystem
+        let staging_dir = db_dir.with_file_name("snapshot");
+        Self {
+            source,
+            db_dir,
+            staging_dir,
+            progress_tracker,
+        }
+    }
+
+    /// Must only be called while the local node is stopped.
+    /// Returns the manifest the snapshot was verified against, if any
+    pub async fn run(&self) -> Result<Option<SnapshotManifest>, anyhow::Error> {
+        info!(target: LOG_TARGET, "Bootstrapping node database from snapshot: {:?}", self.source);
+        let result = self.run_inner().await;
+        if let Err(e) = fs::remove_dir_all(&self.staging_dir).await {
+            if self.staging_dir.exists() {
+                warn!(target: LOG_TARGET, "Could not remove snapshot staging dir: {e}");
+            }
+        }
+        result
+    }
+
+    async fn run_inner(&self) -> Result<Option<SnapshotManifest>, anyhow::Error> {
+        fs::create_dir_all(&self.staging_dir).await?;
+
+        let (manifest, archive_path) = match &self.source {
+            SnapshotSource::Download { manifest_url } => {
+                let manifest = Self::download_manifest(manifest_url).await?;
+                let archive_path = self.download_archive(&manifest).await?;
+                (Some(manifest), archive_path)
+            }
+            SnapshotSource::Import {
+                archive_path,
+                manifest_path,
+                ..
+            } => {
+                if !archive_path.exists() {
+                    return Err(anyhow!(
+                        "Snapshot archive does not exist: {}",
+                        archive_path.display()
+                    ));
+                }
+                let manifest =
+                    Self::load_local_manifest(archive_path, manifest_path.as_deref()).await?;
+                (manifest, archive_path.clone())
+            }
+        };
+
+        match &manifest {
+            Some(manifest) => self.verify(manifest, &archive_path).await?,
+            None if self.allows_unverified() => {
+                warn!(target: LOG_TARGET, "No manifest found for {}, importing without verification", archive_path.display());
+            }
+            None => {
+                return Err(anyhow!(
+                    "No manifest found for {}, refusing to import an unverified snapshot",
+                    archive_path.display()
+                ));
+            }
+        }
+
+        let staged_db_dir = self.unpack(&archive_path).await?;
+        self.send_update(BootstrapStage::Swap, 1.0, EXTRACT_STAGE_END)
+            .await;
+        swap_database(&self.db_dir, &staged_db_dir).await?;
+        self.send_update(BootstrapStage::Swap, 1.0, 1.0).await;
+
+        info!(target: LOG_TARGET, "Node database bootstrapped from snapshot{}", manifest.as_ref().map(|m| format!(" at height {}", m.height)).unwrap_or_default());
+        Ok(manifest)
+    }
+
+    fn allows_unverified(&self) -> bool {
+        matches!(
+            self.source,
+            SnapshotSource::Import {
+                allow_unverified: true,
+                ..
+            }
+        )
+    }
+
+    async fn download_manifest(manifest_url: &str) -> Result<SnapshotManifest, anyhow::Error> {
+        let manifest = HttpClient::with_retries(3)
+            .send_get_request(manifest_url)
+            .await?
+            .json::<SnapshotManifest>()
+            .await?;
+        Ok(manifest)
+    }
+
+    async fn load_local_manifest(
+        archive_path: &Path,
+        manifest_path: Option<&Path>,
+    ) -> Result<Option<SnapshotManifest>, anyhow::Error> {
+        let manifest_path = match manifest_path {
+            Some(manifest_path) => manifest_path.to_path_buf(),
+            None => {
+                let mut sidecar = archive_path.as_os_str().to_owned();
+                sidecar.push(".manifest.json");
+                let sidecar = PathBuf::from(sidecar);
+                if !sidecar.exists() {
+                    return Ok(None);
+                }
+                sidecar
+            }
+        };
+
+        let contents = fs::read_to_string(&manifest_path).await?;
+        Ok(Some(serde_json::from_str(&contents)?))
+    }
+
+    async fn download_archive(
+        &self,
+        manifest: &SnapshotManifest,
+    ) -> Result<PathBuf, anyhow::Error> {
+        let archive_url = manifest
+            .archive_url
+            .clone()
+            .ok_or_else(|| anyhow!("Snapshot manifest does not contain an archive url"))?;
+
+        let (progress_tx, mut progress_rx) = watch::channel(0f64);
+        let progress_tracker = self.progress_tracker.clone();
+        let progress_handle = tokio::spawn(async move {
+            while progress_rx.changed().await.is_ok() {
+                let percentage = *progress_rx.borrow();
+                if let Some(tracker) = &progress_tracker {
+                    tracker
+                        .send_update(
+                            stage_params(BootstrapStage::Download, percentage),
+                            DOWNLOAD_STAGE_END * percentage / 100.0,
+                        )
+                        .await;
+                }
+            }
+        });
+
+        let result = HttpFileClient::builder()
+            .with_progress_status_sender(Some(progress_tx))
+            .with_download_resume()
+            .build(archive_url, self.staging_dir.clone())?
+            .execute()
+            .await;
+        let _unused = progress_handle.await;
+
+        result
+    }
+
+    async fn verify(
+        &self,
+        manifest: &SnapshotManifest,
+        archive_path: &Path,
+    ) -> Result<(), anyhow::Error> {
+        let network = Network::get_current();
+        if !manifest.network.eq_ignore_ascii_case(network.as_key_str()) {
+            return Err(anyhow!(
+                "Snapshot is for network {} but the node runs on {}",
+                manifest.network,
+                network.as_key_str()
+            ));
+        }
+
+        self.send_update(BootstrapStage::Verify, 0.0, DOWNLOAD_STAGE_END)
+            .await;
+        let checksum = sha256_file(archive_path).await?;
+        if !checksum.eq_ignore_ascii_case(&manifest.checksum) {
+            return Err(anyhow!(
+                "Snapshot checksum mismatch: expected {}, got {checksum}",
+                manifest.checksum
+            ));
+        }
+
+        match get_block_info_from_block_scan(network, &manifest.height).await {
+            Ok((_, block_hash)) if !block_hash.eq_ignore_ascii_case(&manifest.block_hash) => {
+                return Err(anyhow!(
+                    "Snapshot block {} at height {} is not on the main chain (expected {block_hash})",
+                    manifest.block_hash,
+                    manifest.height
+                ));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Could not confirm snapshot block hash with block explorer: {e}");
+            }
+        }
+        self.send_update(BootstrapStage::Verify, 100.0, VERIFY_STAGE_END)
+            .await;
+
+        Ok(())
+    }
+
+    async fn unpack(&self, archive_path: &Path) -> Result<PathBuf, anyhow::Error> {
+        let extract_dir = self.staging_dir.join("extracted");
+        if extract_dir.exists() {
+            fs::remove_dir_all(&extract_dir).await?;
+        }
+        fs::create_dir_all(&extract_dir).await?;
+
+        self.send_update(BootstrapStage::Extract, 0.0, VERIFY_STAGE_END)
+            .await;
+        if is_archive(archive_path) {
+            extract(archive_path, &extract_dir).await?;
+        } else {
+            // Older backups are a bare LMDB data file under any name
+            fs::copy(archive_path, extract_dir.join(LMDB_DATA_FILE)).await?;
+        }
+
+        let staged_db_dir = find_lmdb_dir(&extract_dir).await?;
+        self.send_update(BootstrapStage::Extract, 100.0, EXTRACT_STAGE_END)
+            .await;
+
+        Ok(staged_db_dir)
+    }
+
+    async fn send_update(&self, stage: BootstrapStage, stage_percentage: f64, completion: f64) {
+        if let Some(tracker) = &self.progress_tracker {
+            tracker
+                .send_update(stage_params(stage, stage_percentage), completion)
+                .await;
+        }
+    }
+}
+
+fn stage_params(stage: BootstrapStage, percentage: f64) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    params.insert("stage".to_string(), stage.as_str().to_string());
+    params.insert("progress".to_string(), format!("{percentage:.0}"));
+    params
+}
+
+fn is_archive(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ARCHIVE_EXTENSIONS.contains(&ext))
+}
+
+async fn sha256_file(path: &Path) -> Result<String, anyhow::Error> {
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; CHECKSUM_BUFFER_SIZE];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+async fn find_lmdb_dir(extract_dir: &Path) -> Result<PathBuf, anyhow::Error> {
+    if extract_dir.join(LMDB_DATA_FILE).exists() {
+        return Ok(extract_dir.to_path_buf());
+    }
+    let mut entries = fs::read_dir(extract_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path.is_dir() && path.join(LMDB_DATA_FILE).exists() {
+            return Ok(path);
+        }
+    }
+    Err(anyhow!(
+        "Snapshot archive does not contain {LMDB_DATA_FILE}"
+    ))
+}
+
+async fn swap_database(db_dir: &Path, staged_db_dir: &Path) -> Result<(), anyhow::Error> {
+    let backup_dir = db_dir.with_file_name("db.pre_snapshot");
+    if backup_dir.exists() {
+        fs::remove_dir_all(&backup_dir).await?;
+    }
+
+    let had_existing_db = db_dir.exists();
+    if had_existing_db {
+        fs::rename(db_dir, &backup_dir).await?;
+    } else if let Some(parent) = db_dir.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+
+    if let Err(e) = fs::rename(staged_db_dir, db_dir).await {
+        error!(target: LOG_TARGET, "Could not move snapshot into place, rolling back: {e}");
+        if had_existing_db {
+            fs::rename(&backup_dir, db_dir).await.map_err(|rollback_error| {
+                anyhow!("Could not move snapshot into place ({e}) and rollback failed: {rollback_error}")
+            })?;
+        }
+        return Err(e.into());
+    }
+
+    if had_existing_db {
+        let _unused = fs::remove_dir_all(&backup_dir).await.inspect_err(|e| {
+            warn!(target: LOG_TARGET, "Could not remove previous node database: {e}");
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use rand::random;
+
+    async fn test_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("snapshot_bootstrap_{}", random::<u64>()));
+        fs::create_dir_all(&dir).await.unwrap();
+        dir
+    }
+
+    async fn write_db(dir: &Path, contents: &str) {
+        fs::create_dir_all(dir).await.unwrap();
+        fs::write(dir.join(LMDB_DATA_FILE), contents).await.unwrap();
+    }
+
+    async fn read_db(dir: &Path) -> String {
+        fs::read_to_string(dir.join(LMDB_DATA_FILE)).await.unwrap()
+    }
+
+    #[test]
+    fn treats_only_known_extensions_as_archives() {
+        assert!(is_archive(Path::new("snapshot.tar.gz")));
+        assert!(is_archive(Path::new("snapshot.zip")));
+        assert!(!is_archive(Path::new("data.mdb")));
+        assert!(!is_archive(Path::new("backup")));
+    }
+
+    #[tokio::test]
+    async fn finds_lmdb_dir_at_root_or_one_level_down() {
+        let dir = test_dir().await;
+        let root = dir.join("root");
+        write_db(&root, "root").await;
+        assert_eq!(find_lmdb_dir(&root).await.unwrap(), root);
+
+        let nested = dir.join("nested");
+        write_db(&nested.join("db"), "nested").await;
+        assert_eq!(find_lmdb_dir(&nested).await.unwrap(), nested.join("db"));
+
+        let empty = dir.join("empty");
+        fs::create_dir_all(empty.join("db")).await.unwrap();
+        assert!(find_lmdb_dir(&empty).await.is_err());
+
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn swaps_staged_db_over_existing_db() {
+        let dir = test_dir().await;
+        let db_dir = dir.join("db");
+        let staged_db_dir = dir.join("snapshot");
+        write_db(&db_dir, "old").await;
+        write_db(&staged_db_dir, "new").await;
+
+        swap_database(&db_dir, &staged_db_dir).await.unwrap();
+
+        assert_eq!(read_db(&db_dir).await, "new");
+        assert!(!staged_db_dir.exists());
+        assert!(!dir.join("db.pre_snapshot").exists());
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn swaps_into_missing_db_dir() {
+        let dir = test_dir().await;
+        let db_dir = dir.join("base_node").join("db");
+        let staged_db_dir = dir.join("snapshot");
+        write_db(&staged_db_dir, "new").await;
+
+        swap_database(&db_dir, &staged_db_dir).await.unwrap();
+
+        assert_eq!(read_db(&db_dir).await, "new");
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn restores_existing_db_when_swap_fails() {
+        let dir = test_dir().await;
+        let db_dir = dir.join("db");
+        write_db(&db_dir, "old").await;
+
+        assert!(swap_database(&db_dir, &dir.join("missing")).await.is_err());
+
+        assert_eq!(read_db(&db_dir).await, "old");
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
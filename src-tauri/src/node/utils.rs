@@ -20,101 +20,89 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::events::BackgroundNodeSyncUpdatePayload;
+use crate::utils::sync_progress::SyncRateEstimator;
 use minotari_node_grpc_client::grpc::{SyncProgressResponse, SyncState};
-use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tari_common::configuration::Network;
+
+pub(crate) fn get_node_network_dir(base_path: &Path) -> PathBuf {
+    base_path
+        .join("node")
+        .join(Network::get_current().to_string().to_lowercase())
+}
+
+/// Custom node data dirs hold one database per network, the default one lives in the node's working dir
+pub(crate) fn get_node_db_dir(base_path: &Path, node_data_dir: Option<&Path>) -> PathBuf {
+    match node_data_dir {
+        Some(node_data_dir) => node_data_dir
+            .join(Network::get_current().to_string().to_lowercase())
+            .join("db"),
+        None => get_node_network_dir(base_path)
+            .join("data")
+            .join("base_node")
+            .join("db"),
+    }
+}
 
 #[derive(Debug, Clone)]
 pub(crate) struct SyncProgressInfo {
-    pub progress_params: HashMap<String, String>,
+    pub update: BackgroundNodeSyncUpdatePayload,
     pub percentage: f64,
 }
 
+/// Keeps separate rates for the header and block stages so switching stages does not skew the ETA
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NodeSyncRateEstimator {
+    headers: SyncRateEstimator,
+    blocks: SyncRateEstimator,
+}
+
 impl SyncProgressInfo {
     pub fn from_sync_progress(
         sync_progress: &SyncProgressResponse,
         required_sync_peers: u32,
+        rate_estimator: &mut NodeSyncRateEstimator,
     ) -> Self {
-        let mut progress_params = HashMap::new();
         let mut percentage = 0f64;
 
-        match sync_progress.state {
+        let update = match sync_progress.state {
             x if x == SyncState::Startup as i32 => {
                 percentage =
                     sync_progress.initial_connected_peers as f64 / f64::from(required_sync_peers);
-                progress_params.insert("step".to_string(), "Startup".to_string());
-                progress_params.insert(
-                    "initial_connected_peers".to_string(),
-                    sync_progress.initial_connected_peers.to_string(),
-                );
-                progress_params.insert(
-                    "required_peers".to_string(),
-                    required_sync_peers.to_string(),
-                );
+                BackgroundNodeSyncUpdatePayload::Startup {
+                    initial_connected_peers: sync_progress.initial_connected_peers,
+                    required_peers: required_sync_peers,
+                }
             }
             x if x == SyncState::Header as i32 => {
                 percentage = sync_progress.local_height as f64 / sync_progress.tip_height as f64;
-                progress_params.insert("step".to_string(), "Header".to_string());
-                progress_params.insert(
-                    "local_header_height".to_string(),
-                    sync_progress.local_height.to_string(),
-                );
-                progress_params.insert(
-                    "tip_header_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
-                progress_params.insert("local_block_height".to_string(), "0".to_string());
-                progress_params.insert(
-                    "tip_block_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
-                // Keep these fields for old translations that have not been updated
-                progress_params.insert(
-                    "local_height".to_string(),
-                    sync_progress.local_height.to_string(),
-                );
-                progress_params.insert(
-                    "tip_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
+                BackgroundNodeSyncUpdatePayload::Header {
+                    local_header_height: sync_progress.local_height,
+                    tip_header_height: sync_progress.tip_height,
+                    local_block_height: 0,
+                    tip_block_height: sync_progress.tip_height,
+                    headers_rate: rate_estimator
+                        .headers
+                        .update(sync_progress.local_height, sync_progress.tip_height),
+                }
             }
             x if x == SyncState::Block as i32 => {
                 percentage = sync_progress.local_height as f64 / sync_progress.tip_height as f64;
-                progress_params.insert("step".to_string(), "Block".to_string());
-                progress_params.insert(
-                    "local_header_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
-                progress_params.insert(
-                    "tip_header_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
-                progress_params.insert(
-                    "local_block_height".to_string(),
-                    sync_progress.local_height.to_string(),
-                );
-                progress_params.insert(
-                    "tip_block_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
-                // Keep these fields for old translations that have not been updated
-                progress_params.insert(
-                    "local_height".to_string(),
-                    sync_progress.local_height.to_string(),
-                );
-                progress_params.insert(
-                    "tip_height".to_string(),
-                    sync_progress.tip_height.to_string(),
-                );
-            }
-            x if x == SyncState::Done as i32 => {
-                progress_params.insert("step".to_string(), "Done".to_string());
+                BackgroundNodeSyncUpdatePayload::Block {
+                    local_header_height: sync_progress.tip_height,
+                    tip_header_height: sync_progress.tip_height,
+                    local_block_height: sync_progress.local_height,
+                    tip_block_height: sync_progress.tip_height,
+                    blocks_rate: rate_estimator
+                        .blocks
+                        .update(sync_progress.local_height, sync_progress.tip_height),
+                }
             }
-            _ => {}
-        }
+            x if x == SyncState::Done as i32 => BackgroundNodeSyncUpdatePayload::Done,
+            _ => BackgroundNodeSyncUpdatePayload::Unknown,
+        };
 
-        Self {
-            progress_params,
-            percentage,
-        }
+        Self { update, percentage }
     }
 }
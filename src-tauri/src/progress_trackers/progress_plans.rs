@@ -32,6 +32,7 @@ pub enum SetupStep {
     BinariesWallet,
     StartTor,
     MigratingDatabase,
+    BootstrapSnapshot,
     StartingNode,
 
     // Cpu Mining Phase
@@ -64,6 +65,7 @@ impl SetupStep {
             Self::BinariesNode => "binaries-node".to_string(),
             Self::StartTor => "start-tor".to_string(),
             Self::MigratingDatabase => "migrating-database".to_string(),
+            Self::BootstrapSnapshot => "bootstrap-snapshot".to_string(),
             Self::StartingNode => "starting-node".to_string(),
 
             // Cpu Mining Phase
@@ -98,7 +100,8 @@ impl SetupStep {
             Self::BinariesNode => 5,
             Self::StartTor => 5,
             Self::MigratingDatabase => 2,
-            Self::StartingNode => 23,
+            Self::BootstrapSnapshot => 3,
+            Self::StartingNode => 20,
 
             // Cpu Mining Phase 20
             Self::BinariesCpuMiner => 8,
@@ -83,6 +83,7 @@ pub enum SetupFeature {
     CpuPool,
     GpuPool,
     Restarting,
+    SignerOnly,
 }
 
 impl Display for SetupFeature {
@@ -92,6 +93,7 @@ impl Display for SetupFeature {
             SetupFeature::CpuPool => write!(f, "CPU Pool"),
             SetupFeature::GpuPool => write!(f, "GPU Pool"),
             SetupFeature::Restarting => write!(f, "Restarting"),
+            SetupFeature::SignerOnly => write!(f, "Signer only"),
         }
     }
 }
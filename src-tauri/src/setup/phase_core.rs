@@ -20,6 +20,7 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use log::error;
 use tari_shutdown::ShutdownSignal;
 use tauri::{AppHandle, Manager};
 use tokio::sync::{
@@ -30,6 +31,7 @@ use tokio_util::task::TaskTracker;
 
 use crate::{
     auto_launcher::AutoLauncher,
+    binaries::{Binaries, BinaryResolver},
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
     progress_trackers::{
         progress_plans::SetupStep,
@@ -42,13 +44,12 @@ use crate::{
 };
 
 use super::{
-    listeners::SetupFeaturesList,
+    listeners::{SetupFeature, SetupFeaturesList},
     setup_manager::PhaseStatus,
     trait_setup_phase::{SetupConfiguration, SetupPhaseImpl},
     utils::{setup_default_adapter::SetupDefaultAdapter, timeout_watcher::TimeoutWatcher},
 };
 
-#[allow(dead_code)]
 static LOG_TARGET: &str = "tari::universe::phase_core";
 
 #[derive(Clone, Default)]
@@ -62,7 +63,6 @@ pub struct CoreSetupPhase {
     app_configuration: CoreSetupPhaseAppConfiguration,
     setup_configuration: SetupConfiguration,
     status_sender: Sender<PhaseStatus>,
-    #[allow(dead_code)]
     setup_features: SetupFeaturesList,
     timeout_watcher: TimeoutWatcher,
 }
@@ -188,6 +188,19 @@ impl SetupPhaseImpl for CoreSetupPhase {
             })
             .await?;
 
+        // The wallet phase does not run on a signer, but signing needs the wallet binary
+        if self
+            .setup_features
+            .is_feature_enabled(SetupFeature::SignerOnly)
+        {
+            if let Err(e) = BinaryResolver::current()
+                .initialize_binary(Binaries::Wallet, None)
+                .await
+            {
+                error!(target: LOG_TARGET, "Failed to initialize the wallet binary for signing: {e}");
+            }
+        }
+
         Ok(())
     }
 
@@ -20,14 +20,25 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, time::Duration};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
 use crate::{
     binaries::{Binaries, BinaryResolver},
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    events::BackgroundNodeSyncUpdatePayload,
     events_emitter::EventsEmitter,
     events_manager::EventsManager,
-    node::node_manager::{NodeManagerError, STOP_ON_ERROR_CODES},
+    node::{
+        chain_tip_verifier::stop_mining_on_minority_chain,
+        network_stats_history::{NetworkStatsHistory, NetworkStatsSample, MAX_SAMPLES},
+        node_manager::{NodeManagerError, STOP_ON_ERROR_CODES},
+        orphan_chain_recovery::{run_orphan_chain_recovery, OrphanChainRecoveryState},
+        snapshot_bootstrap::{SnapshotBootstrap, SnapshotBootstrapResultPayload},
+        utils::get_node_db_dir,
+    },
     progress_trackers::{
         progress_plans::SetupStep,
         progress_stepper::{ProgressStepper, ProgressStepperBuilder},
@@ -57,11 +68,13 @@ use super::{
 };
 
 static LOG_TARGET: &str = "tari::universe::phase_hardware";
+const NETWORK_STATS_HEADERS_COUNT: u64 = 10;
 
 #[derive(Clone, Default)]
 pub struct NodeSetupPhaseAppConfiguration {
     use_tor: bool,
     base_node_grpc_address: String,
+    node_data_dir: Option<PathBuf>,
 }
 
 pub struct NodeSetupPhase {
@@ -134,6 +147,7 @@ impl SetupPhaseImpl for NodeSetupPhase {
             .add_incremental_step(SetupStep::BinariesNode, true)
             .add_step(SetupStep::StartTor, true)
             .add_incremental_step(SetupStep::MigratingDatabase, true)
+            .add_incremental_step(SetupStep::BootstrapSnapshot, false)
             .add_step(SetupStep::StartingNode, true)
             .add_incremental_step(SetupStep::StartingNode, true)
             .build(
@@ -148,10 +162,12 @@ impl SetupPhaseImpl for NodeSetupPhase {
         let config_core = ConfigCore::content().await;
         let use_tor = *config_core.use_tor();
         let base_node_grpc_address = config_core.remote_base_node_address().clone();
+        let node_data_dir = config_core.node_data_dir().clone();
 
         Ok(NodeSetupPhaseAppConfiguration {
             use_tor,
             base_node_grpc_address,
+            node_data_dir,
         })
     }
 
@@ -211,6 +227,32 @@ impl SetupPhaseImpl for NodeSetupPhase {
             })
             .await?;
 
+        let snapshot_tracker =
+            progress_stepper.track_step_incrementally(SetupStep::BootstrapSnapshot);
+        progress_stepper
+            .complete_step(SetupStep::BootstrapSnapshot, || async {
+                if !node_type.is_local() {
+                    return Ok(());
+                }
+                match state.node_manager.take_pending_snapshot().await {
+                    Some(source) => {
+                        let db_dir =
+                            get_node_db_dir(&data_dir, app_configuration.node_data_dir.as_deref());
+                        let result = SnapshotBootstrap::new(source, db_dir, snapshot_tracker)
+                            .run()
+                            .await;
+                        // The step is optional, so without this a failed import would only end up in the logs
+                        EventsEmitter::emit_node_snapshot_bootstrap_result(
+                            SnapshotBootstrapResultPayload::from_result(&result),
+                        )
+                        .await;
+                        result.map(|_| ())
+                    }
+                    None => Ok(()),
+                }
+            })
+            .await?;
+
         progress_stepper.complete_step(SetupStep::StartingNode, || async {
             for _i in 0..2 {
                 let tor_control_port = state.tor_manager.get_control_port().await?;
@@ -365,14 +407,96 @@ impl SetupPhaseImpl for NodeSetupPhase {
                             match check_if_orphan {
                                 Ok(is_stuck) => {
                                     EventsEmitter::emit_stuck_on_orphan_chain(is_stuck).await;
+                                    let recovery_config =
+                                        ConfigCore::content().await.orphan_chain_recovery().clone();
+                                    let grace_period =
+                                        Duration::from_secs(recovery_config.grace_period_secs);
+                                    if recovery_config.enabled
+                                        && state.node_manager.is_local_current().await
+                                        && OrphanChainRecoveryState::current()
+                                            .write()
+                                            .await
+                                            .should_start_recovery(is_stuck, grace_period, Instant::now())
+                                    {
+                                        TasksTrackers::current()
+                                            .common
+                                            .get_task_tracker()
+                                            .await
+                                            .spawn(run_orphan_chain_recovery(app_handle_clone.clone()));
+                                    }
                                 }
                                 Err(ref e) => {
                                     error!(target: LOG_TARGET, "{e}");
                                 }
                             }
+                            match state.node_manager.verify_chain_tip().await {
+                                Ok(Some(verification)) => {
+                                    let is_minority_chain = verification.is_minority_chain;
+                                    EventsEmitter::emit_chain_tip_verification(verification).await;
+                                    if is_minority_chain {
+                                        stop_mining_on_minority_chain(&app_handle_clone).await;
+                                    }
+                                }
+                                Ok(None) => {}
+                                Err(e) => {
+                                    warn!(target: LOG_TARGET, "Chain tip verification failed: {e}");
+                                }
+                            }
                         },
                         _ = shutdown_signal.wait() => {
-                            info!(target: LOG_TARGET, "Stopping periodic orphan chain checks");
+                            info!(target: LOG_TARGET, "Stopping periodic orphan chain and chain tip checks");
+                            break;
+                        }
+                    }
+                }
+            });
+
+        let app_handle_clone: tauri::AppHandle = self.app_handle.clone();
+        TasksTrackers::current()
+            .node_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let state = app_handle_clone.state::<UniverseAppState>().inner();
+                let mut node_status_watch_rx = (*state.node_status_watch_rx).clone();
+                let mut shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
+
+                loop {
+                    tokio::select! {
+                        _ = node_status_watch_rx.changed() => {
+                            let node_status = *node_status_watch_rx.borrow();
+                            // Lower heights are reorgs, which `record` handles by dropping the replaced blocks
+                            let latest_height = NetworkStatsHistory::current().read().await.latest_height();
+                            if !node_status.is_synced || latest_height == Some(node_status.block_height) {
+                                continue;
+                            }
+                            if NetworkStatsHistory::current().read().await.is_empty() {
+                                match state.node_manager.get_recent_blocks(MAX_SAMPLES as u64).await {
+                                    Ok(blocks) => {
+                                        NetworkStatsHistory::current()
+                                            .write()
+                                            .await
+                                            .backfill(NetworkStatsSample::from_headers(&blocks));
+                                    }
+                                    Err(e) => {
+                                        warn!(target: LOG_TARGET, "Failed to backfill network stats from headers: {e}");
+                                    }
+                                }
+                            }
+                            // Enough blocks to find the newest one of every algorithm
+                            let recent_blocks = state
+                                .node_manager
+                                .get_recent_blocks(NETWORK_STATS_HEADERS_COUNT)
+                                .await
+                                .inspect_err(|e| warn!(target: LOG_TARGET, "Failed to get recent blocks for network stats: {e}"))
+                                .unwrap_or_default();
+                            NetworkStatsHistory::current()
+                                .write()
+                                .await
+                                .record(NetworkStatsSample::new(&node_status, &recent_blocks));
+                        },
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Stopping network stats sampling");
                             break;
                         }
                     }
@@ -393,39 +517,44 @@ impl SetupPhaseImpl for NodeSetupPhase {
 }
 
 async fn wait_node_synced_with_progress(app_handle: tauri::AppHandle) -> Result<(), anyhow::Error> {
-    let (progress_params_tx, mut progress_params_rx) =
-        watch::channel(HashMap::<String, String>::new());
+    let (sync_update_tx, mut sync_update_rx) = watch::channel(None);
     let (progress_percentage_tx, progress_percentage_rx) = watch::channel(0f64);
     let mut shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
 
     let progress_handle = TasksTrackers::current()
-            .node_phase
-            .get_task_tracker()
-            .await
-            .spawn(async move {
-                loop {
-                    tokio::select! {
-                        _ = progress_params_rx.changed() => {
-                            let progress_params = progress_params_rx.borrow().clone();
-                            let percentage = *progress_percentage_rx.borrow();
-                            if let Some(step) = progress_params.get("step").cloned() {
-                                EventsEmitter::emit_background_node_sync_update(progress_params.clone()).await;
-                                if step == "Block" && percentage == 1.0 || step == "Done" {
-                                    break;
-                                }
+        .node_phase
+        .get_task_tracker()
+        .await
+        .spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = sync_update_rx.changed() => {
+                        let sync_update = sync_update_rx.borrow().clone();
+                        let percentage = *progress_percentage_rx.borrow();
+                        if let Some(sync_update) = sync_update {
+                            let is_done = match sync_update {
+                                BackgroundNodeSyncUpdatePayload::Block { .. } => percentage == 1.0,
+                                BackgroundNodeSyncUpdatePayload::Done => true,
+                                BackgroundNodeSyncUpdatePayload::Unknown => continue,
+                                _ => false,
+                            };
+                            EventsEmitter::emit_background_node_sync_update(sync_update).await;
+                            if is_done {
+                                break;
                             }
-                        },
-                        _ = shutdown_signal.wait() => {
-                            break;
                         }
+                    },
+                    _ = shutdown_signal.wait() => {
+                        break;
                     }
                 }
-            });
+            }
+        });
 
     let state = app_handle.state::<UniverseAppState>();
     let _unused = state
         .node_manager
-        .wait_synced(&progress_params_tx, &progress_percentage_tx)
+        .wait_synced(&sync_update_tx, &progress_percentage_tx)
         .await
         .err();
     progress_handle.abort();
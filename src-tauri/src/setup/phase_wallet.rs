@@ -35,7 +35,7 @@ use crate::{
     },
     setup::setup_manager::SetupPhase,
     tasks_tracker::TasksTrackers,
-    wallet::wallet_manager::WalletStartupConfig,
+    wallet::{auto_sweep::run_auto_sweep, wallet_manager::WalletStartupConfig},
     UniverseAppState,
 };
 use anyhow::Error;
@@ -239,6 +239,16 @@ impl SetupPhaseImpl for WalletSetupPhase {
                 .wallet_manager
                 .wait_for_initial_wallet_scan(node_status_watch_rx)
                 .await?;
+
+            // Nothing can be swept without the seed
+            if !InternalWallet::is_watch_only().await {
+                let app_handle = self.get_app_handle().clone();
+                TasksTrackers::current()
+                    .wallet_phase
+                    .get_task_tracker()
+                    .await
+                    .spawn(run_auto_sweep(app_handle));
+            }
         }
 
         let config_wallet = ConfigWallet::content().await;
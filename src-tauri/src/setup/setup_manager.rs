@@ -352,7 +352,14 @@ impl SetupManager {
                 }
             } else {
                 let _unused = ConfigUI::set_wallet_ui_mode(WalletUIMode::Standard).await;
-                match InternalWallet::initialize_with_seed(&app_handle).await {
+                let initialized = match ConfigWallet::content().await.watch_only_package() {
+                    Some(package) => {
+                        info!(target: LOG_TARGET, "Watch-only wallet for {}", package.tari_address);
+                        InternalWallet::initialize_watch_only(&app_handle, package.clone()).await
+                    }
+                    None => InternalWallet::initialize_with_seed(&app_handle).await,
+                };
+                match initialized {
                     Ok(()) => {
                         if let Err(e) = ConfigWallet::migrate().await {
                             EventsEmitter::emit_critical_problem(CriticalProblemPayload {
@@ -482,12 +489,21 @@ impl SetupManager {
             features.add_feature(SetupFeature::GpuPool);
         }
 
-        let external_tari_address = ConfigWallet::content()
-            .await
-            .selected_external_tari_address()
-            .clone();
-        // Seedless Wallet feature
-        if external_tari_address.is_some() || is_exchange_miner_build {
+        let config_wallet = ConfigWallet::content().await;
+        let external_tari_address = config_wallet.selected_external_tari_address().clone();
+        // Signer only feature
+        if *config_wallet.signer_only_mode() {
+            info!(target: LOG_TARGET, "Signer only feature enabled");
+            features.add_feature(SetupFeature::SignerOnly);
+            EventsEmitter::emit_disabled_phases(vec![
+                SetupPhase::CpuMining,
+                SetupPhase::GpuMining,
+                SetupPhase::Node,
+                SetupPhase::Wallet,
+            ])
+            .await;
+        } else if external_tari_address.is_some() || is_exchange_miner_build {
+            // Seedless Wallet feature
             info!(target: LOG_TARGET, "Seedless wallet feature enabled");
             features.add_feature(SetupFeature::SeedlessWallet);
             EventsEmitter::emit_disabled_phases(vec![SetupPhase::Wallet]).await;
@@ -588,6 +604,12 @@ impl SetupManager {
 
     pub async fn shutdown_phases(&self, phases: Vec<SetupPhase>) {
         for phase in phases {
+            if setup_features.is_feature_enabled(SetupFeature::SignerOnly)
+                && phase != SetupPhase::Core
+            {
+                info!(target: LOG_TARGET, "Skipping {phase:?} Phase as Signer only mode is enabled");
+                continue;
+            }
             match phase {
                 SetupPhase::Core => {
                     TasksTrackers::current().core_phase.close().await;
@@ -762,6 +784,10 @@ impl SetupManager {
         .await;
 
         self.setup_core_phase().await;
+        if setup_features.is_feature_enabled(SetupFeature::SignerOnly) {
+            info!(target: LOG_TARGET, "Signer only mode, skipping mining, node and wallet phases");
+            return;
+        }
         self.setup_cpu_mining_phase().await;
         self.setup_gpu_mining_phase().await;
         self.setup_node_phase().await;
@@ -0,0 +1,219 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+use crate::configs::config_core::ConfigCore;
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::trait_config::ConfigImpl;
+use crate::node::block_explorer::BlockPowAlgorithm;
+use crate::utils::math_utils::BLOCKS_PER_DAY;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+/// Target block time of a single algorithm
+pub const ALGORITHM_BLOCK_TIME_SECS: u64 = SECONDS_PER_DAY / BLOCKS_PER_DAY;
+/// RandomX and SHA3x each target `ALGORITHM_BLOCK_TIME_SECS`
+const MINING_ALGORITHMS: u64 = 2;
+/// Average interval between two blocks of any algorithm
+pub const EXPECTED_BLOCK_TIME_SECS: u64 = ALGORITHM_BLOCK_TIME_SECS / MINING_ALGORITHMS;
+// Longer gaps between status updates mean mining was stopped, they do not count towards the expected blocks
+const MAX_STATUS_UPDATE_GAP: Duration = Duration::from_secs(60);
+
+static LUCK_TRACKER: LazyLock<RwLock<SoloMiningLuckTracker>> =
+    LazyLock::new(|| RwLock::new(SoloMiningLuckTracker::default()));
+
+/// Whether `pow_algo` is mined solo, so a found block pays this wallet in full. Pool and p2pool
+/// rewards are shared, they count towards neither the expected nor the found blocks. Read from the
+/// config on every call so a pool change is picked up while mining.
+pub async fn is_solo_mining(pow_algo: BlockPowAlgorithm) -> bool {
+    if *ConfigCore::content().await.is_p2pool_enabled() {
+        return false;
+    }
+    let config_pools = ConfigPools::content().await;
+    match pow_algo {
+        BlockPowAlgorithm::RandomXM => !*config_pools.cpu_pool_enabled(),
+        BlockPowAlgorithm::Sha3x => !*config_pools.gpu_pool_enabled(),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq)]
+pub struct SoloMiningEstimate {
+    pub expected_seconds_to_block: Option<u64>,
+    pub expected_blocks_per_day: f64,
+    pub probability_block_in_day: f64,
+    pub probability_block_in_week: f64,
+    /// Blocks found divided by blocks expected while solo mining the algorithm since the app
+    /// started, 1.0 is average luck
+    pub luck: Option<f64>,
+}
+
+/// Block discovery is a Poisson process, the chance of a block in `t` seconds is `1 - e^(-t / expected_time)`
+pub fn estimate_solo_mining(
+    hash_rate: f64,
+    network_hash_rate: u64,
+    block_time_secs: u64,
+) -> SoloMiningEstimate {
+    let blocks_per_second = blocks_per_second(hash_rate, network_hash_rate, block_time_secs);
+    if blocks_per_second <= 0.0 {
+        return SoloMiningEstimate::default();
+    }
+
+    let probability_within = |seconds: u64| {
+        #[allow(clippy::cast_precision_loss)]
+        let expected_blocks = blocks_per_second * seconds as f64;
+        1.0 - (-expected_blocks).exp()
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    SoloMiningEstimate {
+        expected_seconds_to_block: Some((1.0 / blocks_per_second).round() as u64),
+        expected_blocks_per_day: blocks_per_second * SECONDS_PER_DAY as f64,
+        probability_block_in_day: probability_within(SECONDS_PER_DAY),
+        probability_block_in_week: probability_within(7 * SECONDS_PER_DAY),
+        luck: None,
+    }
+}
+
+fn blocks_per_second(hash_rate: f64, network_hash_rate: u64, block_time_secs: u64) -> f64 {
+    if hash_rate <= 0.0 || network_hash_rate == 0 || block_time_secs == 0 {
+        return 0.0;
+    }
+    // The network estimate lags behind, a miner can not hold more than the whole network
+    #[allow(clippy::cast_precision_loss)]
+    let share = (hash_rate / network_hash_rate as f64).min(1.0);
+    #[allow(clippy::cast_precision_loss)]
+    let blocks_per_second = share / block_time_secs as f64;
+    blocks_per_second
+}
+
+/// Accumulates the blocks each solo miner should have found so far to compare them with the
+/// blocks it actually found
+#[derive(Debug, Default)]
+pub struct SoloMiningLuckTracker {
+    expected_blocks: HashMap<BlockPowAlgorithm, f64>,
+    blocks_found: HashMap<BlockPowAlgorithm, u64>,
+    last_updates: HashMap<BlockPowAlgorithm, Instant>,
+}
+
+impl SoloMiningLuckTracker {
+    pub fn current() -> &'static RwLock<SoloMiningLuckTracker> {
+        &LUCK_TRACKER
+    }
+
+    pub fn record_hashrate(
+        &mut self,
+        pow_algo: BlockPowAlgorithm,
+        hash_rate: f64,
+        network_hash_rate: u64,
+    ) {
+        let now = Instant::now();
+        if let Some(last_update) = self.last_updates.insert(pow_algo, now) {
+            let elapsed = now.duration_since(last_update);
+            if elapsed <= MAX_STATUS_UPDATE_GAP {
+                self.add_expected_blocks(
+                    pow_algo,
+                    blocks_per_second(hash_rate, network_hash_rate, ALGORITHM_BLOCK_TIME_SECS)
+                        * elapsed.as_secs_f64(),
+                );
+            }
+        }
+    }
+
+    /// Only blocks found by a solo miner count, pool and p2pool rewards are shared
+    pub fn record_block_found(&mut self, pow_algo: BlockPowAlgorithm) {
+        *self.blocks_found.entry(pow_algo).or_default() += 1;
+    }
+
+    pub fn luck(&self, pow_algo: BlockPowAlgorithm) -> Option<f64> {
+        let expected_blocks = self.expected_blocks.get(&pow_algo).copied()?;
+        if expected_blocks <= 0.0 {
+            return None;
+        }
+        let blocks_found = self
+            .blocks_found
+            .get(&pow_algo)
+            .copied()
+            .unwrap_or_default();
+        #[allow(clippy::cast_precision_loss)]
+        Some(blocks_found as f64 / expected_blocks)
+    }
+
+    fn add_expected_blocks(&mut self, pow_algo: BlockPowAlgorithm, blocks: f64) {
+        *self.expected_blocks.entry(pow_algo).or_default() += blocks;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn one_percent_of_the_network_finds_a_block_every_hundred_block_times() {
+        let estimate = estimate_solo_mining(1_000.0, 100_000, 240);
+
+        assert_eq!(estimate.expected_seconds_to_block, Some(24_000));
+        assert!((estimate.expected_blocks_per_day - 3.6).abs() < 1e-9);
+        assert!((estimate.probability_block_in_day - (1.0 - (-3.6f64).exp())).abs() < 1e-9);
+        assert!(estimate.probability_block_in_week > estimate.probability_block_in_day);
+    }
+
+    #[test]
+    fn no_hashrate_gives_empty_estimate() {
+        assert_eq!(
+            estimate_solo_mining(0.0, 100_000, 240),
+            SoloMiningEstimate::default()
+        );
+        assert_eq!(
+            estimate_solo_mining(1_000.0, 0, 240),
+            SoloMiningEstimate::default()
+        );
+    }
+
+    #[test]
+    fn share_is_capped_at_the_whole_network() {
+        let estimate = estimate_solo_mining(500_000.0, 100_000, 240);
+
+        assert_eq!(estimate.expected_seconds_to_block, Some(240));
+    }
+
+    #[test]
+    fn luck_is_tracked_per_algorithm() {
+        let mut tracker = SoloMiningLuckTracker::default();
+        tracker.add_expected_blocks(BlockPowAlgorithm::RandomXM, 2.0);
+        tracker.add_expected_blocks(BlockPowAlgorithm::Sha3x, 0.5);
+        tracker.record_block_found(BlockPowAlgorithm::Sha3x);
+
+        assert_eq!(tracker.luck(BlockPowAlgorithm::RandomXM), Some(0.0));
+        assert_eq!(tracker.luck(BlockPowAlgorithm::Sha3x), Some(2.0));
+        assert_eq!(tracker.luck(BlockPowAlgorithm::RandomXT), None);
+    }
+}
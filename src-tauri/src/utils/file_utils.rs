@@ -23,6 +23,7 @@
 use std::path::{Component, Path, PathBuf};
 
 use anyhow::anyhow;
+use sysinfo::Disks;
 
 /// Returns a relative path from one path to another.
 pub fn make_relative_path(root: &Path, current: &Path) -> PathBuf {
@@ -62,3 +63,41 @@ pub fn convert_to_string(path: PathBuf) -> Result<String, anyhow::Error> {
         .map(|s| s.to_string())
         .ok_or_else(|| anyhow!("Could not convert path to string"))
 }
+
+/// Returns the total size in bytes of all files under the given directory.
+pub fn get_dir_size(path: &Path) -> Result<u64, anyhow::Error> {
+    let mut size = 0;
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                size += metadata.len();
+            }
+        }
+    }
+    Ok(size)
+}
+
+/// Returns the available space in bytes on the disk the given path would be stored on.
+pub fn get_available_space(path: &Path) -> Result<u64, anyhow::Error> {
+    let mut existing_path = path;
+    while !existing_path.exists() {
+        existing_path = existing_path
+            .parent()
+            .ok_or_else(|| anyhow!("No existing parent for path: {}", path.display()))?;
+    }
+    let existing_path = dunce::canonicalize(existing_path)?;
+
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| existing_path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().components().count())
+        .map(|disk| disk.available_space())
+        .ok_or_else(|| anyhow!("Could not find disk for path: {}", path.display()))
+}
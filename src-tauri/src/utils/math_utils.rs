@@ -23,7 +23,7 @@
 use log::warn;
 use tari_transaction_components::tari_amount::MicroMinotari;
 
-const BLOCKS_PER_DAY: u64 = 360; // both RandomX and SHA3 produce 360 blocks per day - 720 in total
+pub(crate) const BLOCKS_PER_DAY: u64 = 360; // both RandomX and SHA3 produce 360 blocks per day - 720 in total
 const LOG_TARGET: &str = "tari::universe::math_utils";
 
 pub fn estimate_earning(
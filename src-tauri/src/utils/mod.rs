@@ -24,6 +24,7 @@ pub mod address_utils;
 pub mod app_flow_utils;
 pub mod commands_builder;
 pub mod cryptography;
+pub mod csv_utils;
 pub mod file_utils;
 pub mod formatting_utils;
 pub mod locks_utils;
@@ -33,6 +34,7 @@ pub mod math_utils;
 pub mod network_status;
 pub mod platform_utils;
 pub mod rand_utils;
+pub mod sync_progress;
 
 pub mod system_status;
 #[cfg(windows)]
@@ -0,0 +1,147 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+// Weight of the newest sample, lower values give a steadier but slower reacting ETA
+const RATE_SMOOTHING_FACTOR: f64 = 0.3;
+// Progress is polled about every second, closer samples make the rate jump around
+const MIN_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct SyncRate {
+    pub items_per_second: f64,
+    pub eta_seconds: Option<u64>,
+}
+
+/// Tracks how fast a counter (headers, blocks, scanned heights) moves towards a target
+#[derive(Debug, Clone, Default)]
+pub struct SyncRateEstimator {
+    last_sample: Option<(Instant, u64)>,
+    smoothed_rate: Option<f64>,
+}
+
+impl SyncRateEstimator {
+    pub fn update(&mut self, current: u64, total: u64) -> Option<SyncRate> {
+        self.update_at(Instant::now(), current, total)
+    }
+
+    pub fn reset(&mut self) {
+        self.last_sample = None;
+        self.smoothed_rate = None;
+    }
+
+    fn update_at(&mut self, now: Instant, current: u64, total: u64) -> Option<SyncRate> {
+        match self.last_sample {
+            Some((last_time, last_current)) if current >= last_current => {
+                let elapsed = now.saturating_duration_since(last_time);
+                if elapsed >= MIN_SAMPLE_INTERVAL {
+                    #[allow(clippy::cast_precision_loss)]
+                    let sample_rate = (current - last_current) as f64 / elapsed.as_secs_f64();
+                    self.smoothed_rate = Some(match self.smoothed_rate {
+                        Some(rate) => rate + RATE_SMOOTHING_FACTOR * (sample_rate - rate),
+                        None => sample_rate,
+                    });
+                    self.last_sample = Some((now, current));
+                }
+            }
+            // First sample, or the counter went backwards because the sync restarted
+            _ => {
+                self.reset();
+                self.last_sample = Some((now, current));
+            }
+        }
+
+        self.rate(current, total)
+    }
+
+    fn rate(&self, current: u64, total: u64) -> Option<SyncRate> {
+        let items_per_second = self.smoothed_rate?;
+        let remaining = total.saturating_sub(current);
+        #[allow(clippy::cast_precision_loss)]
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        let eta_seconds = if remaining == 0 {
+            Some(0)
+        } else if items_per_second > 0.0 {
+            Some((remaining as f64 / items_per_second).ceil() as u64)
+        } else {
+            None
+        };
+
+        Some(SyncRate {
+            items_per_second,
+            eta_seconds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn steady_progress_gives_rate_and_eta() {
+        let start = Instant::now();
+        let mut estimator = SyncRateEstimator::default();
+
+        assert_eq!(estimator.update_at(start, 0, 1000), None);
+        let rate = estimator
+            .update_at(start + Duration::from_secs(2), 100, 1000)
+            .unwrap();
+
+        assert!((rate.items_per_second - 50.0).abs() < f64::EPSILON);
+        assert_eq!(rate.eta_seconds, Some(18));
+    }
+
+    #[test]
+    fn rate_is_smoothed_across_samples() {
+        let start = Instant::now();
+        let mut estimator = SyncRateEstimator::default();
+
+        estimator.update_at(start, 0, 1000);
+        estimator.update_at(start + Duration::from_secs(1), 100, 1000);
+        let rate = estimator
+            .update_at(start + Duration::from_secs(2), 100, 1000)
+            .unwrap();
+
+        assert!((rate.items_per_second - 70.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn counter_going_backwards_resets_the_rate() {
+        let start = Instant::now();
+        let mut estimator = SyncRateEstimator::default();
+
+        estimator.update_at(start, 500, 1000);
+        estimator.update_at(start + Duration::from_secs(1), 600, 1000);
+
+        assert_eq!(
+            estimator.update_at(start + Duration::from_secs(2), 10, 1000),
+            None
+        );
+    }
+}
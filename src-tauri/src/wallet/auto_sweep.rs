@@ -0,0 +1,425 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tari_transaction_components::tari_amount::MicroMinotari;
+use tauri::{AppHandle, Manager};
+
+use crate::configs::config_wallet::{
+    ConfigWallet, ConfigWalletContent, ExternalTariAddressBookRecord,
+};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::pin::PinManager;
+use crate::tasks_tracker::TasksTrackers;
+use crate::wallet::contact_book;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::auto_sweep";
+const AUTO_SWEEP_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+pub const MIN_AUTO_SWEEP_INTERVAL_SECS: u64 = 10 * 60;
+pub const MAX_AUTO_SWEEP_LOG_ENTRIES: usize = 100;
+const PIN_LOCKED_ERROR: &str =
+    "Sweep rules can't be enabled while the wallet is protected by a PIN";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AutoSweepRule {
+    pub id: String,
+    pub enabled: bool,
+    /// Name of the address book contact that receives the sweep
+    pub destination: String,
+    /// Available balance that has to be reached before sweeping
+    pub threshold: MicroMinotari,
+    /// Kept in the wallet after the sweep, on top of the fee
+    pub reserve: MicroMinotari,
+    pub min_interval_secs: u64,
+    pub payment_id: Option<String>,
+    pub last_attempt_at: Option<u64>,
+}
+
+impl Default for AutoSweepRule {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            enabled: false,
+            destination: String::new(),
+            threshold: MicroMinotari(0),
+            reserve: MicroMinotari(0),
+            min_interval_secs: 24 * 60 * 60,
+            payment_id: None,
+            last_attempt_at: None,
+        }
+    }
+}
+
+impl AutoSweepRule {
+    pub fn validate(
+        &self,
+        book: &HashMap<String, ExternalTariAddressBookRecord>,
+    ) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("Sweep rule id can't be empty".to_string());
+        }
+        if !book.contains_key(&self.destination) {
+            return Err(format!(
+                "Sweep destination {} is not in the address book",
+                self.destination
+            ));
+        }
+        if self.threshold <= self.reserve {
+            return Err("Sweep threshold must be greater than the reserve".to_string());
+        }
+        if self.min_interval_secs < MIN_AUTO_SWEEP_INTERVAL_SECS {
+            return Err(format!(
+                "Sweep interval must be at least {MIN_AUTO_SWEEP_INTERVAL_SECS} seconds"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_due(&self, now: u64) -> bool {
+        self.enabled
+            && self
+                .last_attempt_at
+                .is_none_or(|last| now.saturating_sub(last) >= self.min_interval_secs)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AutoSweepStatus {
+    Sent,
+    Skipped { reason: String },
+    Failed { error: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AutoSweepLogEntry {
+    pub rule_id: String,
+    pub timestamp: u64,
+    pub destination: String,
+    pub amount: MicroMinotari,
+    #[serde(flatten)]
+    pub status: AutoSweepStatus,
+}
+
+/// Validates new rules, keeping the last attempt time of rules that already existed. Sweeps run
+/// unattended, so no rule can be enabled while a PIN is set and nobody may be around to enter it.
+pub fn merge_rules(
+    existing: &[AutoSweepRule],
+    rules: Vec<AutoSweepRule>,
+    book: &HashMap<String, ExternalTariAddressBookRecord>,
+    pin_locked: bool,
+) -> Result<Vec<AutoSweepRule>, String> {
+    let mut ids = HashSet::new();
+    rules
+        .into_iter()
+        .map(|mut rule| {
+            rule.validate(book)?;
+            if pin_locked && rule.enabled {
+                return Err(PIN_LOCKED_ERROR.to_string());
+            }
+            if !ids.insert(rule.id.clone()) {
+                return Err(format!("Duplicate sweep rule id {}", rule.id));
+            }
+            rule.last_attempt_at = existing
+                .iter()
+                .find(|existing| existing.id == rule.id)
+                .and_then(|existing| existing.last_attempt_at);
+            Ok(rule)
+        })
+        .collect()
+}
+
+/// Amount to sweep once `available` reaches `threshold`, leaving `reserve` and the fee behind
+pub fn sweep_amount(
+    available: MicroMinotari,
+    threshold: MicroMinotari,
+    reserve: MicroMinotari,
+    fee: MicroMinotari,
+) -> Option<MicroMinotari> {
+    if available < threshold {
+        return None;
+    }
+    available
+        .as_u64()
+        .checked_sub(reserve.as_u64())
+        .and_then(|amount| amount.checked_sub(fee.as_u64()))
+        .filter(|amount| *amount > 0)
+        .map(MicroMinotari)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Checks the sweep rules periodically while the wallet phase runs
+pub async fn run_auto_sweep(app_handle: AppHandle) {
+    let mut interval = tokio::time::interval(AUTO_SWEEP_CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut shutdown_signal = TasksTrackers::current().wallet_phase.get_signal().await;
+
+    loop {
+        tokio::select! {
+            _ = shutdown_signal.wait() => {
+                info!(target: LOG_TARGET, "Shutdown signal received, stopping auto sweep");
+                break;
+            }
+            _ = interval.tick() => {
+                check_auto_sweep_rules(&app_handle).await;
+            }
+        }
+    }
+}
+
+/// Runs the first due rule, one sweep per check since every sweep changes the available balance
+async fn check_auto_sweep_rules(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+    if !state.wallet_manager.is_initial_scan_completed() {
+        return;
+    }
+    let now = now_secs();
+    if PinManager::pin_locked().await {
+        disable_rules_for_pin(now).await;
+        return;
+    }
+    let config = ConfigWallet::content().await;
+    let Some(rule) = config
+        .auto_sweep_rules()
+        .iter()
+        .find(|rule| rule.is_due(now))
+        .cloned()
+    else {
+        return;
+    };
+
+    let Some(contact) = config.external_tari_addresses_book().get(&rule.destination) else {
+        warn!(target: LOG_TARGET, "Sweep rule {} destination {} is no longer in the address book", rule.id, rule.destination);
+        record_sweep(AutoSweepLogEntry {
+            rule_id: rule.id,
+            timestamp: now,
+            destination: rule.destination,
+            amount: MicroMinotari(0),
+            status: AutoSweepStatus::Failed {
+                error: "Destination is not in the address book".to_string(),
+            },
+        })
+        .await;
+        return;
+    };
+    let destination = contact.address.to_base58();
+
+    let result = state
+        .wallet_manager
+        .sweep_available_balance(
+            destination.clone(),
+            rule.threshold,
+            rule.reserve,
+            rule.payment_id.clone(),
+            app_handle,
+        )
+        .await;
+    let (amount, status) = match result {
+        Ok(None) => return,
+        Ok(Some(amount)) => {
+            info!(target: LOG_TARGET, "Sweep rule {} sent {amount} to {destination}", rule.id);
+            contact_book::mark_contact_used(&destination).await;
+            if let Ok(balance) = state.wallet_manager.get_balance().await {
+                EventsEmitter::emit_wallet_balance_update(balance).await;
+            }
+            (amount, AutoSweepStatus::Sent)
+        }
+        Err(e) => {
+            error!(target: LOG_TARGET, "Sweep rule {} to {destination} failed: {e}", rule.id);
+            (
+                MicroMinotari(0),
+                AutoSweepStatus::Failed {
+                    error: e.to_string(),
+                },
+            )
+        }
+    };
+
+    record_sweep(AutoSweepLogEntry {
+        rule_id: rule.id,
+        timestamp: now,
+        destination,
+        amount,
+        status,
+    })
+    .await;
+}
+
+/// Rules enabled before a PIN was set would be skipped on every check, they are disabled once and
+/// the user has to remove the PIN to enable them again
+async fn disable_rules_for_pin(now: u64) {
+    let mut rules = ConfigWallet::content().await.auto_sweep_rules().clone();
+    let enabled_rules: Vec<AutoSweepRule> =
+        rules.iter().filter(|rule| rule.enabled).cloned().collect();
+    if enabled_rules.is_empty() {
+        return;
+    }
+
+    warn!(target: LOG_TARGET, "Disabling {} sweep rules, the wallet is protected by a PIN", enabled_rules.len());
+    for rule in &mut rules {
+        rule.enabled = false;
+    }
+    if let Err(e) =
+        ConfigWallet::update_field(ConfigWalletContent::set_auto_sweep_rules, rules).await
+    {
+        error!(target: LOG_TARGET, "Failed to disable sweep rules: {e}");
+        return;
+    }
+    for rule in enabled_rules {
+        record_sweep(AutoSweepLogEntry {
+            rule_id: rule.id,
+            timestamp: now,
+            destination: rule.destination,
+            amount: MicroMinotari(0),
+            status: AutoSweepStatus::Skipped {
+                reason: "Disabled, the wallet is protected by a PIN".to_string(),
+            },
+        })
+        .await;
+    }
+}
+
+async fn record_sweep(entry: AutoSweepLogEntry) {
+    if let Err(e) =
+        ConfigWallet::update_field(ConfigWalletContent::record_auto_sweep, entry.clone()).await
+    {
+        error!(target: LOG_TARGET, "Failed to record sweep: {e}");
+    }
+    EventsEmitter::emit_auto_sweep_update(entry).await;
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use std::str::FromStr;
+    use tari_common_types::tari_address::TariAddress;
+
+    const ESME_ONE_SIDED_ADDRESS: &str = "f25eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF3g";
+
+    fn rule(last_attempt_at: Option<u64>) -> AutoSweepRule {
+        AutoSweepRule {
+            id: "cold".to_string(),
+            enabled: true,
+            min_interval_secs: 3600,
+            last_attempt_at,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sweep_amount_leaves_reserve_and_fee() {
+        let threshold = MicroMinotari(1_000_000);
+        let reserve = MicroMinotari(100_000);
+        let fee = MicroMinotari(130);
+
+        assert_eq!(
+            sweep_amount(MicroMinotari(999_999), threshold, reserve, fee),
+            None
+        );
+        assert_eq!(
+            sweep_amount(MicroMinotari(1_500_000), threshold, reserve, fee),
+            Some(MicroMinotari(1_399_870))
+        );
+        assert_eq!(
+            sweep_amount(MicroMinotari(100_100), MicroMinotari(0), reserve, fee),
+            None
+        );
+    }
+
+    #[test]
+    fn rule_is_due_after_interval() {
+        assert!(rule(None).is_due(10_000));
+        assert!(!rule(Some(9_000)).is_due(10_000));
+        assert!(rule(Some(6_400)).is_due(10_000));
+        assert!(!AutoSweepRule {
+            enabled: false,
+            ..rule(None)
+        }
+        .is_due(10_000));
+    }
+
+    #[test]
+    fn merge_keeps_last_attempt_and_rejects_duplicates() {
+        let address = TariAddress::from_str(ESME_ONE_SIDED_ADDRESS).unwrap();
+        let book = HashMap::from([(
+            "Cold wallet".to_string(),
+            ExternalTariAddressBookRecord {
+                name: "Cold wallet".to_string(),
+                address,
+                labels: vec![],
+                notes: String::new(),
+                default_payment_id: None,
+                last_used_at: None,
+            },
+        )]);
+        let new_rule = AutoSweepRule {
+            destination: "Cold wallet".to_string(),
+            threshold: MicroMinotari(1_000_000),
+            min_interval_secs: MIN_AUTO_SWEEP_INTERVAL_SECS,
+            ..rule(None)
+        };
+        let existing = AutoSweepRule {
+            last_attempt_at: Some(42),
+            ..new_rule.clone()
+        };
+
+        let merged = merge_rules(&[existing], vec![new_rule.clone()], &book, false).unwrap();
+        assert_eq!(
+            merged.first().and_then(|rule| rule.last_attempt_at),
+            Some(42)
+        );
+
+        let err =
+            merge_rules(&[], vec![new_rule.clone(), new_rule.clone()], &book, false).unwrap_err();
+        assert!(err.contains("Duplicate"));
+
+        let missing = AutoSweepRule {
+            destination: "Unknown".to_string(),
+            ..new_rule.clone()
+        };
+        assert!(merge_rules(&[], vec![missing], &book, false).is_err());
+
+        assert_eq!(
+            merge_rules(&[], vec![new_rule.clone()], &book, true).unwrap_err(),
+            PIN_LOCKED_ERROR
+        );
+        let disabled = AutoSweepRule {
+            enabled: false,
+            ..new_rule
+        };
+        assert!(merge_rules(&[], vec![disabled], &book, true).is_ok());
+    }
+}
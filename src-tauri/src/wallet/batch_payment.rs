@@ -0,0 +1,154 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use tari_common_types::tari_address::TariAddressFeatures;
+use tari_transaction_components::tari_amount::{MicroMinotari, Minotari};
+
+use crate::utils::address_utils::verify_send;
+
+pub const MAX_BATCH_RECIPIENTS: usize = 100;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchPaymentRecipient {
+    pub address: String,
+    /// Amount in XTM, as entered by the user
+    pub amount: String,
+    pub payment_id: Option<String>,
+}
+
+impl BatchPaymentRecipient {
+    /// Checks the address accepts one-sided payments and returns the amount in MicroMinotari
+    pub fn validate(&self) -> Result<MicroMinotari, String> {
+        verify_send(self.address.clone(), TariAddressFeatures::ONE_SIDED)?;
+        let amount = Minotari::from_str(&self.amount)
+            .map(MicroMinotari::from)
+            .map_err(|e| format!("Invalid amount: {e}"))?;
+        if amount.as_u64() == 0 {
+            return Err("Amount must be greater than zero".to_string());
+        }
+        Ok(amount)
+    }
+
+    /// Payment ID can't be an empty string
+    pub fn payment_id(&self) -> Option<String> {
+        self.payment_id
+            .clone()
+            .filter(|payment_id| !payment_id.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BatchPaymentStatus {
+    Sent,
+    /// The recipient did not pass validation and nothing was sent
+    Invalid {
+        error: String,
+    },
+    /// Building, signing or broadcasting the transaction failed
+    Failed {
+        error: String,
+    },
+    /// Not attempted because the remaining balance could not cover it
+    Skipped {
+        error: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchPaymentResult {
+    pub index: usize,
+    pub address: String,
+    pub amount: String,
+    #[serde(flatten)]
+    pub status: BatchPaymentStatus,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BatchPaymentSummary {
+    pub results: Vec<BatchPaymentResult>,
+    pub sent_count: usize,
+    pub failed_count: usize,
+    pub total_sent: MicroMinotari,
+}
+
+impl BatchPaymentSummary {
+    pub fn push(&mut self, recipient: &BatchPaymentRecipient, status: BatchPaymentStatus) {
+        match status {
+            BatchPaymentStatus::Sent => self.sent_count += 1,
+            _ => self.failed_count += 1,
+        }
+        self.results.push(BatchPaymentResult {
+            index: self.results.len(),
+            address: recipient.address.clone(),
+            amount: recipient.amount.clone(),
+            status,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ESME_ONE_SIDED_ADDRESS: &str = "f25eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF3g";
+
+    fn recipient(address: &str, amount: &str) -> BatchPaymentRecipient {
+        BatchPaymentRecipient {
+            address: address.to_string(),
+            amount: amount.to_string(),
+            payment_id: Some(String::new()),
+        }
+    }
+
+    #[test]
+    fn validates_address_and_amount() {
+        assert_eq!(
+            recipient(ESME_ONE_SIDED_ADDRESS, "1.5").validate(),
+            Ok(MicroMinotari(1_500_000))
+        );
+        assert!(recipient(ESME_ONE_SIDED_ADDRESS, "0").validate().is_err());
+        assert!(recipient(ESME_ONE_SIDED_ADDRESS, "abc").validate().is_err());
+        assert!(recipient("invalid_address", "1").validate().is_err());
+        assert_eq!(recipient(ESME_ONE_SIDED_ADDRESS, "1").payment_id(), None);
+    }
+
+    #[test]
+    fn summary_counts_results_in_order() {
+        let mut summary = BatchPaymentSummary::default();
+        let recipient = recipient(ESME_ONE_SIDED_ADDRESS, "1");
+        summary.push(&recipient, BatchPaymentStatus::Sent);
+        summary.push(
+            &recipient,
+            BatchPaymentStatus::Failed {
+                error: "broadcast failed".to_string(),
+            },
+        );
+
+        assert_eq!(summary.sent_count, 1);
+        assert_eq!(summary.failed_count, 1);
+        assert_eq!(summary.results.last().map(|result| result.index), Some(1));
+    }
+}
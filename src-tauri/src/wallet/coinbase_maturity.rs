@@ -0,0 +1,221 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::LazyLock;
+
+use serde::Serialize;
+use tari_common::configuration::Network;
+use tari_transaction_components::tari_amount::MicroMinotari;
+use tokio::sync::RwLock;
+
+use crate::solo_mining_estimator::EXPECTED_BLOCK_TIME_SECS;
+use crate::wallet::wallet_types::TransactionInfo;
+
+static INSTANCE: LazyLock<RwLock<CoinbaseMaturityTracker>> =
+    LazyLock::new(|| RwLock::new(CoinbaseMaturityTracker::default()));
+
+/// Blocks a coinbase output stays time locked, per network. The app does not depend on
+/// `tari_core`, so this table mirrors `coinbase_min_maturity` in
+/// `base_layer/core/src/consensus/consensus_constants.rs` of the tari release the node and wallet
+/// gRPC clients are pinned to. Update it together with that release.
+const COINBASE_MATURITY_BLOCKS: [(Network, u64); 6] = [
+    (Network::MainNet, 720),
+    (Network::StageNet, 720),
+    (Network::NextNet, 6),
+    (Network::LocalNet, 6),
+    (Network::Igor, 6),
+    (Network::Esmeralda, 6),
+];
+
+pub fn coinbase_maturity_blocks(network: Network) -> u64 {
+    COINBASE_MATURITY_BLOCKS
+        .iter()
+        .find(|(table_network, _)| *table_network == network)
+        .map(|(_, blocks)| *blocks)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CoinbaseOutputMaturity {
+    pub tx_id: String,
+    pub amount: MicroMinotari,
+    pub mined_height: u64,
+    pub maturity_height: u64,
+    pub blocks_remaining: u64,
+    /// Unix timestamp the output is expected to become spendable at
+    pub estimated_unlock_timestamp: u64,
+    pub is_mature: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CoinbaseRewardsMaturedPayload {
+    pub block_height: u64,
+    pub count: usize,
+    pub amount: MicroMinotari,
+}
+
+/// Mined coinbase outputs ordered by the height they unlock at
+pub fn coinbase_maturity_schedule(
+    transactions: &[TransactionInfo],
+    current_height: u64,
+    now: u64,
+    maturity_blocks: u64,
+) -> Vec<CoinbaseOutputMaturity> {
+    let mut schedule: Vec<CoinbaseOutputMaturity> = transactions
+        .iter()
+        .filter(|tx| tx.status.is_coinbase() && !tx.is_cancelled && tx.mined_in_block_height > 0)
+        .map(|tx| {
+            let maturity_height = tx.mined_in_block_height + maturity_blocks;
+            let blocks_remaining = maturity_height.saturating_sub(current_height);
+            CoinbaseOutputMaturity {
+                tx_id: tx.tx_id.clone(),
+                amount: tx.amount,
+                mined_height: tx.mined_in_block_height,
+                maturity_height,
+                blocks_remaining,
+                estimated_unlock_timestamp: now + blocks_remaining * EXPECTED_BLOCK_TIME_SECS,
+                is_mature: blocks_remaining == 0,
+            }
+        })
+        .collect();
+    schedule.sort_by(|a, b| {
+        a.maturity_height
+            .cmp(&b.maturity_height)
+            .then_with(|| a.tx_id.cmp(&b.tx_id))
+    });
+    schedule
+}
+
+/// Remembers up to which height matured rewards were reported, so every batch is reported once
+#[derive(Debug, Default)]
+pub struct CoinbaseMaturityTracker {
+    checked_height: Option<u64>,
+}
+
+impl CoinbaseMaturityTracker {
+    pub fn current() -> &'static RwLock<CoinbaseMaturityTracker> {
+        &INSTANCE
+    }
+
+    /// Outputs that unlocked since the previous check. The first check only records the height,
+    /// rewards that were already spendable at startup are not reported.
+    pub fn advance(
+        &mut self,
+        schedule: &[CoinbaseOutputMaturity],
+        current_height: u64,
+    ) -> Option<CoinbaseRewardsMaturedPayload> {
+        let previous_height = self.checked_height.replace(current_height)?;
+        if current_height <= previous_height {
+            return None;
+        }
+
+        let matured: Vec<&CoinbaseOutputMaturity> = schedule
+            .iter()
+            .filter(|output| {
+                output.maturity_height > previous_height && output.maturity_height <= current_height
+            })
+            .collect();
+        if matured.is_empty() {
+            return None;
+        }
+        Some(CoinbaseRewardsMaturedPayload {
+            block_height: current_height,
+            count: matured.len(),
+            amount: matured
+                .iter()
+                .fold(MicroMinotari(0), |total, output| total + output.amount),
+        })
+    }
+
+    pub fn reset(&mut self) {
+        self.checked_height = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::wallet::wallet_types::TransactionStatus;
+
+    fn coinbase(tx_id: &str, mined_height: u64, amount: u64) -> TransactionInfo {
+        TransactionInfo {
+            tx_id: tx_id.to_string(),
+            source_address: String::new(),
+            dest_address: String::new(),
+            status: TransactionStatus::CoinbaseConfirmed,
+            amount: MicroMinotari(amount),
+            is_cancelled: false,
+            direction: 1,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp: 0,
+            payment_id: String::new(),
+            mined_in_block_height: mined_height,
+            payment_reference: None,
+        }
+    }
+
+    #[test]
+    fn schedule_lists_coinbase_outputs_by_maturity() {
+        let mut transfer = coinbase("3", 95, 500);
+        transfer.status = TransactionStatus::MinedConfirmed;
+        let schedule = coinbase_maturity_schedule(
+            &[coinbase("2", 99, 200), coinbase("1", 90, 100), transfer],
+            100,
+            1_000,
+            6,
+        );
+
+        assert_eq!(schedule.len(), 2);
+        let first = schedule.first().unwrap();
+        assert_eq!(first.tx_id, "1");
+        assert!(first.is_mature);
+        assert_eq!(first.estimated_unlock_timestamp, 1_000);
+        let second = schedule.get(1).unwrap();
+        assert_eq!(second.maturity_height, 105);
+        assert_eq!(second.blocks_remaining, 5);
+        assert_eq!(
+            second.estimated_unlock_timestamp,
+            1_000 + 5 * EXPECTED_BLOCK_TIME_SECS
+        );
+    }
+
+    #[test]
+    fn tracker_reports_each_matured_batch_once() {
+        let transactions = [
+            coinbase("1", 90, 100),
+            coinbase("2", 92, 200),
+            coinbase("3", 99, 300),
+        ];
+        let mut tracker = CoinbaseMaturityTracker::default();
+
+        let schedule = coinbase_maturity_schedule(&transactions, 97, 0, 6);
+        assert_eq!(tracker.advance(&schedule, 97), None);
+
+        let schedule = coinbase_maturity_schedule(&transactions, 99, 0, 6);
+        let matured = tracker.advance(&schedule, 99).unwrap();
+        assert_eq!(matured.count, 1);
+        assert_eq!(matured.amount, MicroMinotari(200));
+        assert_eq!(tracker.advance(&schedule, 99), None);
+    }
+}
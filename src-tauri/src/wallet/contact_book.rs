@@ -0,0 +1,357 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tari_common_types::tari_address::TariAddress;
+
+use crate::configs::config_wallet::{
+    ConfigWallet, ConfigWalletContent, ExternalTariAddressBookRecord,
+    EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK,
+};
+use crate::configs::trait_config::ConfigImpl;
+use crate::utils::address_utils::verify_tari_address;
+use crate::utils::csv_utils::{parse_csv, to_csv_line, unescape_csv_field};
+use crate::wallet::wallet_types::TariAddressVariants;
+
+const LOG_TARGET: &str = "tari::universe::contact_book";
+const CSV_HEADER: [&str; 5] = ["name", "address", "labels", "notes", "default_payment_id"];
+// Labels share a single CSV column
+const CSV_LABEL_SEPARATOR: char = ';';
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContactBookFileFormat {
+    Csv,
+    Json,
+}
+
+/// A contact as entered by the user or read from an import file, the address may be in emoji,
+/// base58 or hex form
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactInput {
+    pub name: String,
+    pub address: String,
+    pub labels: Vec<String>,
+    pub notes: String,
+    pub default_payment_id: Option<String>,
+}
+
+impl ContactInput {
+    pub fn into_record(
+        self,
+        last_used_at: Option<u64>,
+    ) -> Result<ExternalTariAddressBookRecord, String> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Contact name can't be empty".to_string());
+        }
+        let address = verify_tari_address(self.address.trim())?;
+
+        Ok(ExternalTariAddressBookRecord {
+            name,
+            address,
+            labels: self
+                .labels
+                .into_iter()
+                .map(|label| label.trim().to_string())
+                .filter(|label| !label.is_empty())
+                .collect(),
+            notes: self.notes,
+            default_payment_id: self.default_payment_id.filter(|id| !id.is_empty()),
+            last_used_at,
+        })
+    }
+}
+
+impl From<&ExternalTariAddressBookRecord> for ContactInput {
+    fn from(record: &ExternalTariAddressBookRecord) -> Self {
+        Self {
+            name: record.name.clone(),
+            address: record.address.to_base58(),
+            labels: record.labels.clone(),
+            notes: record.notes.clone(),
+            default_payment_id: record.default_payment_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Contact {
+    pub name: String,
+    pub address: TariAddressVariants,
+    pub labels: Vec<String>,
+    pub notes: String,
+    pub default_payment_id: Option<String>,
+    pub last_used_at: Option<u64>,
+}
+
+impl From<&ExternalTariAddressBookRecord> for Contact {
+    fn from(record: &ExternalTariAddressBookRecord) -> Self {
+        Self {
+            name: record.name.clone(),
+            address: TariAddressVariants::from(&record.address),
+            labels: record.labels.clone(),
+            notes: record.notes.clone(),
+            default_payment_id: record.default_payment_id.clone(),
+            last_used_at: record.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContactImportSummary {
+    pub imported: usize,
+    /// Names of contacts whose address is already in the book
+    pub duplicates: Vec<String>,
+    /// Entries that could not be imported, with the reason
+    pub invalid: Vec<String>,
+}
+
+/// User contacts sorted by name, the record kept for the selected exchange address is left out
+pub fn user_contacts(
+    book: &HashMap<String, ExternalTariAddressBookRecord>,
+) -> Vec<&ExternalTariAddressBookRecord> {
+    let mut contacts: Vec<_> = book
+        .values()
+        .filter(|record| record.name != EXCHANGES_RECORD_NAME_FOR_EXTERNAL_ADDRESS_BOOK)
+        .collect();
+    contacts.sort_by_key(|record| record.name.to_lowercase());
+    contacts
+}
+
+/// Finds a contact with the same address, whatever form it was entered in
+pub fn find_duplicate<'a>(
+    book: &'a HashMap<String, ExternalTariAddressBookRecord>,
+    address: &TariAddress,
+    except_name: Option<&str>,
+) -> Option<&'a ExternalTariAddressBookRecord> {
+    let base58 = TariAddressVariants::from(address).base58;
+    book.values().find(|record| {
+        Some(record.name.as_str()) != except_name
+            && TariAddressVariants::from(&record.address).base58 == base58
+    })
+}
+
+/// Validates imported contacts against the book and each other, returning the records to add
+pub fn plan_import(
+    book: &HashMap<String, ExternalTariAddressBookRecord>,
+    inputs: Vec<ContactInput>,
+) -> (Vec<ExternalTariAddressBookRecord>, ContactImportSummary) {
+    let mut planned = book.clone();
+    let mut records = Vec::new();
+    let mut summary = ContactImportSummary::default();
+
+    for input in inputs {
+        let name = input.name.clone();
+        let record = match input.into_record(None) {
+            Ok(record) => record,
+            Err(e) => {
+                summary.invalid.push(format!("{name}: {e}"));
+                continue;
+            }
+        };
+        if find_duplicate(&planned, &record.address, None).is_some() {
+            summary.duplicates.push(record.name);
+            continue;
+        }
+        if planned.contains_key(&record.name) {
+            summary
+                .invalid
+                .push(format!("{name}: a contact with this name already exists"));
+            continue;
+        }
+
+        planned.insert(record.name.clone(), record.clone());
+        records.push(record);
+    }
+    summary.imported = records.len();
+
+    (records, summary)
+}
+
+pub fn export_contacts(
+    records: &[ExternalTariAddressBookRecord],
+    format: ContactBookFileFormat,
+) -> Result<String, anyhow::Error> {
+    let contacts: Vec<ContactInput> = records.iter().map(ContactInput::from).collect();
+    match format {
+        ContactBookFileFormat::Json => Ok(serde_json::to_string_pretty(&contacts)?),
+        ContactBookFileFormat::Csv => {
+            let mut csv = to_csv_line(&CSV_HEADER);
+            csv.push('\n');
+            for contact in contacts {
+                csv.push_str(&to_csv_line(&[
+                    contact.name,
+                    contact.address,
+                    contact.labels.join(&CSV_LABEL_SEPARATOR.to_string()),
+                    contact.notes,
+                    contact.default_payment_id.unwrap_or_default(),
+                ]));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+    }
+}
+
+pub fn parse_contacts(
+    content: &str,
+    format: ContactBookFileFormat,
+) -> Result<Vec<ContactInput>, anyhow::Error> {
+    match format {
+        ContactBookFileFormat::Json => Ok(serde_json::from_str(content)?),
+        ContactBookFileFormat::Csv => {
+            let mut rows = parse_csv(content).into_iter();
+            let header = rows
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("Contacts file is empty"))?;
+            let column = |name: &str| header.iter().position(|column| column.trim() == name);
+            let name_column =
+                column("name").ok_or_else(|| anyhow::anyhow!("Missing name column"))?;
+            let address_column =
+                column("address").ok_or_else(|| anyhow::anyhow!("Missing address column"))?;
+            let (labels_column, notes_column, payment_id_column) = (
+                column("labels"),
+                column("notes"),
+                column("default_payment_id"),
+            );
+
+            Ok(rows
+                .map(|row| {
+                    let field = |index: Option<usize>| {
+                        index
+                            .and_then(|index| row.get(index))
+                            .map(|field| unescape_csv_field(field).to_string())
+                            .unwrap_or_default()
+                    };
+                    ContactInput {
+                        name: field(Some(name_column)),
+                        address: field(Some(address_column)),
+                        labels: field(labels_column)
+                            .split(CSV_LABEL_SEPARATOR)
+                            .map(str::to_string)
+                            .collect(),
+                        notes: field(notes_column),
+                        default_payment_id: Some(field(payment_id_column)),
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+/// Records that a payment went to `address`, if it belongs to a contact
+pub async fn mark_contact_used(address: &str) {
+    let Ok(address) = verify_tari_address(address) else {
+        return;
+    };
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    if let Err(e) =
+        ConfigWallet::update_field(ConfigWalletContent::mark_contact_used, (address, timestamp))
+            .await
+    {
+        warn!(target: LOG_TARGET, "Failed to update contact last used time: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use std::str::FromStr;
+
+    const ESME_ONE_SIDED_ADDRESS: &str = "f25eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF3g";
+    const ESME_INTERACTIVE_ADDRESS: &str = "f45eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF31";
+
+    fn input(name: &str, address: &str) -> ContactInput {
+        ContactInput {
+            name: name.to_string(),
+            address: address.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn book_with(name: &str, address: &str) -> HashMap<String, ExternalTariAddressBookRecord> {
+        let record = input(name, address).into_record(None).unwrap();
+        HashMap::from([(record.name.clone(), record)])
+    }
+
+    #[test]
+    fn detects_duplicates_across_address_forms() {
+        let book = book_with("Alice", ESME_ONE_SIDED_ADDRESS);
+        let address = TariAddress::from_str(ESME_ONE_SIDED_ADDRESS).unwrap();
+        let emoji = TariAddress::from_str(&address.to_emoji_string()).unwrap();
+        let hex = TariAddress::from_str(&address.to_hex()).unwrap();
+
+        assert!(find_duplicate(&book, &emoji, None).is_some());
+        assert!(find_duplicate(&book, &hex, None).is_some());
+        assert!(find_duplicate(&book, &hex, Some("Alice")).is_none());
+    }
+
+    #[test]
+    fn import_skips_duplicates_and_invalid_entries() {
+        let book = book_with("Alice", ESME_ONE_SIDED_ADDRESS);
+        let emoji = TariAddress::from_str(ESME_ONE_SIDED_ADDRESS)
+            .unwrap()
+            .to_emoji_string();
+        let (records, summary) = plan_import(
+            &book,
+            vec![
+                input("Alice again", &emoji),
+                input("Bob", ESME_INTERACTIVE_ADDRESS),
+                input("Bob twice", ESME_INTERACTIVE_ADDRESS),
+                input("Carol", "invalid_address"),
+            ],
+        );
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(summary.imported, 1);
+        assert_eq!(summary.duplicates, vec!["Alice again", "Bob twice"]);
+        assert_eq!(summary.invalid.len(), 1);
+    }
+
+    #[test]
+    fn csv_export_round_trips() {
+        let mut record = input("Rig host, north", ESME_ONE_SIDED_ADDRESS)
+            .into_record(None)
+            .unwrap();
+        record.labels = vec!["hosting".to_string(), "monthly".to_string()];
+        record.default_payment_id = Some("invoice 12".to_string());
+
+        let csv = export_contacts(&[record], ContactBookFileFormat::Csv).unwrap();
+        let contacts = parse_contacts(&csv, ContactBookFileFormat::Csv).unwrap();
+        let contact = contacts.first().unwrap();
+
+        assert_eq!(contact.name, "Rig host, north");
+        assert_eq!(contact.labels, vec!["hosting", "monthly"]);
+        assert_eq!(contact.default_payment_id.as_deref(), Some("invoice 12"));
+        assert!(contact.clone().into_record(None).is_ok());
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::Serialize;
+use tari_transaction_components::tari_amount::MicroMinotari;
+
+use crate::node::mempool::MempoolFeePerGramStat;
+
+pub const MIN_FEE_PER_GRAM: u64 = 1;
+/// Upcoming blocks to request mempool fee statistics for, enough to cover the slowest tier
+pub const FEE_STATS_BLOCK_COUNT: u64 = 6;
+// Blocks each tier aims to be mined within
+const FAST_TARGET_BLOCKS: usize = 1;
+const NORMAL_TARGET_BLOCKS: usize = 3;
+const SLOW_TARGET_BLOCKS: usize = 6;
+// Consensus weights are 10 grams per kernel, 53 per output and 8 per input, plus the output
+// scripts and features. This is the kernel with the recipient and change outputs.
+const ESTIMATED_ONE_SIDED_TX_BASE_WEIGHT: u64 = 122;
+const INPUT_WEIGHT: u64 = 8;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct FeeTierEstimate {
+    pub fee_per_gram: MicroMinotari,
+    pub estimated_fee: MicroMinotari,
+    pub target_blocks: usize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct FeeEstimate {
+    pub slow: FeeTierEstimate,
+    pub normal: FeeTierEstimate,
+    pub fast: FeeTierEstimate,
+}
+
+impl FeeEstimate {
+    pub fn from_mempool_stats(stats: &[MempoolFeePerGramStat]) -> Self {
+        Self {
+            slow: tier_estimate(stats, SLOW_TARGET_BLOCKS),
+            normal: tier_estimate(stats, NORMAL_TARGET_BLOCKS),
+            fast: tier_estimate(stats, FAST_TARGET_BLOCKS),
+        }
+    }
+}
+
+/// Lowest fee per gram that outbids the last transaction the mempool would fit into the target
+/// block. When the mempool does not fill the blocks up to the target, the minimum fee is enough.
+pub fn fee_per_gram_for_target(stats: &[MempoolFeePerGramStat], target_blocks: usize) -> u64 {
+    if stats.len() <= target_blocks {
+        return MIN_FEE_PER_GRAM;
+    }
+    stats
+        .get(target_blocks.saturating_sub(1))
+        .map_or(MIN_FEE_PER_GRAM, |stat| {
+            stat.min_fee_per_gram
+                .saturating_add(1)
+                .max(MIN_FEE_PER_GRAM)
+        })
+}
+
+/// Fee of a one-sided transaction spending `input_count` outputs, the fee tiers assume a single
+/// input while a sweep spends every unspent output
+pub fn estimated_fee(fee_per_gram: u64, input_count: u64) -> MicroMinotari {
+    let weight = INPUT_WEIGHT
+        .saturating_mul(input_count.max(1))
+        .saturating_add(ESTIMATED_ONE_SIDED_TX_BASE_WEIGHT);
+    MicroMinotari(fee_per_gram.saturating_mul(weight))
+}
+
+fn tier_estimate(stats: &[MempoolFeePerGramStat], target_blocks: usize) -> FeeTierEstimate {
+    let fee_per_gram = fee_per_gram_for_target(stats, target_blocks);
+    FeeTierEstimate {
+        fee_per_gram: MicroMinotari(fee_per_gram),
+        estimated_fee: estimated_fee(fee_per_gram, 1),
+        target_blocks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat(min_fee_per_gram: u64) -> MempoolFeePerGramStat {
+        MempoolFeePerGramStat {
+            min_fee_per_gram,
+            avg_fee_per_gram: min_fee_per_gram,
+            max_fee_per_gram: min_fee_per_gram,
+        }
+    }
+
+    #[test]
+    fn quiet_mempool_uses_minimum_fee() {
+        let estimate = FeeEstimate::from_mempool_stats(&[stat(5)]);
+
+        assert_eq!(estimate.fast.fee_per_gram, MicroMinotari(MIN_FEE_PER_GRAM));
+        assert_eq!(
+            estimate.normal.fee_per_gram,
+            MicroMinotari(MIN_FEE_PER_GRAM)
+        );
+        assert_eq!(estimate.slow.fee_per_gram, MicroMinotari(MIN_FEE_PER_GRAM));
+        assert_eq!(estimate.fast.estimated_fee, MicroMinotari(130));
+    }
+
+    #[test]
+    fn fee_grows_with_input_count() {
+        assert_eq!(estimated_fee(2, 1), MicroMinotari(260));
+        assert_eq!(estimated_fee(2, 0), MicroMinotari(260));
+        assert_eq!(estimated_fee(2, 10), MicroMinotari(2 * (122 + 80)));
+    }
+
+    #[test]
+    fn congested_mempool_outbids_target_block() {
+        let stats = [stat(40), stat(25), stat(10), stat(4), stat(2)];
+        let estimate = FeeEstimate::from_mempool_stats(&stats);
+
+        assert_eq!(estimate.fast.fee_per_gram, MicroMinotari(41));
+        assert_eq!(estimate.normal.fee_per_gram, MicroMinotari(11));
+        assert_eq!(estimate.slow.fee_per_gram, MicroMinotari(MIN_FEE_PER_GRAM));
+    }
+}
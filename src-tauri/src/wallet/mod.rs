@@ -20,9 +20,22 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod auto_sweep;
+pub mod batch_payment;
+pub mod coinbase_maturity;
+pub mod contact_book;
+pub mod fee_estimation;
+pub mod offline_signing;
+pub mod payment_requests;
 pub mod spend_wallet;
+pub mod stuck_transactions;
+pub mod transaction_export;
+pub mod transaction_history;
+pub mod transaction_search;
 pub mod transaction_service;
 pub mod wallet_adapter;
 pub mod wallet_manager;
+pub mod wallet_rescan;
 pub mod wallet_status_monitor;
 pub mod wallet_types;
+pub mod watch_only;
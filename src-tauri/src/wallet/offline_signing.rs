@@ -0,0 +1,266 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::Path;
+
+use minotari_node_grpc_client::grpc;
+use serde::Serialize;
+use serde_json::Value;
+use tari_transaction_components::transaction_components::Transaction;
+
+/// Fields the offline signer fills in, every other field of the prepared transaction has to come
+/// back unchanged
+const SIGNATURE_FIELDS: [&str; 8] = [
+    "excess",
+    "excess_sig",
+    "metadata_signature",
+    "offset",
+    "range_proof",
+    "script_offset",
+    "script_signature",
+    "signature",
+];
+
+/// Offline transactions prepared on this machine that are waiting for a signed file
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PendingOfflineTransaction {
+    pub tx_id: String,
+    pub created_at: u64,
+}
+
+pub fn unsigned_tx_file_name(tx_id: &str) -> String {
+    format!("{tx_id}-unsigned.json")
+}
+
+pub fn signed_tx_file_name(tx_id: &str) -> String {
+    format!("{tx_id}.json")
+}
+
+/// Reads the transaction ID from an unsigned or signed transaction file
+pub fn read_tx_id(tx_json: &str) -> Result<String, anyhow::Error> {
+    let parsed: serde_json::Value = serde_json::from_str(tx_json)
+        .map_err(|e| anyhow::anyhow!("Transaction file is not valid JSON: {e}"))?;
+    match parsed.get("tx_id") {
+        Some(serde_json::Value::String(tx_id)) => Ok(tx_id.clone()),
+        Some(serde_json::Value::Number(tx_id)) => Ok(tx_id.to_string()),
+        _ => Err(anyhow::anyhow!(
+            "Transaction ID not found in transaction file"
+        )),
+    }
+}
+
+/// Checks that a signed file is the unsigned transaction prepared on this machine with only its
+/// signatures and proofs filled in, so the offline signer can't change recipients, amounts or fees
+pub fn validate_signed_transaction(
+    signed_json: &str,
+    unsigned_json: &str,
+) -> Result<String, anyhow::Error> {
+    let tx_id = read_tx_id(signed_json)?;
+    if read_tx_id(unsigned_json)? != tx_id {
+        return Err(anyhow::anyhow!(
+            "Signed transaction {tx_id} does not match the prepared transaction"
+        ));
+    }
+    let signed: Value = serde_json::from_str(signed_json)?;
+    let unsigned: Value = serde_json::from_str(unsigned_json)?;
+    if signed == unsigned {
+        return Err(anyhow::anyhow!(
+            "Transaction {tx_id} has not been signed yet"
+        ));
+    }
+    if let Some(path) = find_unsigned_difference(&unsigned, &signed, "") {
+        return Err(anyhow::anyhow!(
+            "Signed transaction {tx_id} differs from the prepared transaction at {path}"
+        ));
+    }
+    Ok(tx_id)
+}
+
+/// Signed transaction from a signed file in the node's format, for submitting it to the mempool
+pub fn signed_transaction_for_node(signed_json: &str) -> Result<grpc::Transaction, anyhow::Error> {
+    let parsed: Value = serde_json::from_str(signed_json)
+        .map_err(|e| anyhow::anyhow!("Transaction file is not valid JSON: {e}"))?;
+    let transaction = parsed
+        .get("transaction")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Signed transaction not found in transaction file"))?;
+    let transaction: Transaction = serde_json::from_value(transaction)
+        .map_err(|e| anyhow::anyhow!("Transaction file holds an invalid transaction: {e}"))?;
+    grpc::Transaction::try_from(transaction)
+        .map_err(|e| anyhow::anyhow!("Failed to convert the signed transaction: {e}"))
+}
+
+/// Path of the first difference between the two documents outside of the signature fields
+fn find_unsigned_difference(unsigned: &Value, signed: &Value, path: &str) -> Option<String> {
+    match (unsigned, signed) {
+        (Value::Object(unsigned), Value::Object(signed)) => {
+            let keys = unsigned.keys().chain(signed.keys());
+            for key in keys {
+                if SIGNATURE_FIELDS.contains(&key.as_str()) {
+                    continue;
+                }
+                let field_path = format!("{path}/{key}");
+                match (unsigned.get(key), signed.get(key)) {
+                    (Some(unsigned), Some(signed)) => {
+                        if let Some(difference) =
+                            find_unsigned_difference(unsigned, signed, &field_path)
+                        {
+                            return Some(difference);
+                        }
+                    }
+                    _ => return Some(field_path),
+                }
+            }
+            None
+        }
+        (Value::Array(unsigned), Value::Array(signed)) => {
+            if unsigned.len() != signed.len() {
+                return Some(path.to_string());
+            }
+            unsigned
+                .iter()
+                .zip(signed)
+                .enumerate()
+                .find_map(|(index, (unsigned, signed))| {
+                    find_unsigned_difference(unsigned, signed, &format!("{path}/{index}"))
+                })
+        }
+        (unsigned, signed) if unsigned == signed => None,
+        _ => Some(path.to_string()),
+    }
+}
+
+/// Unsigned transactions in the transactions directory that have no signed counterpart
+pub fn find_pending_offline_transactions(
+    transactions_dir: &Path,
+) -> Result<Vec<PendingOfflineTransaction>, anyhow::Error> {
+    if !transactions_dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut pending = Vec::new();
+    for entry in std::fs::read_dir(transactions_dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(tx_id) = file_name.strip_suffix("-unsigned.json") else {
+            continue;
+        };
+        if transactions_dir.join(signed_tx_file_name(tx_id)).exists() {
+            continue;
+        }
+        let created_at = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        pending.push(PendingOfflineTransaction {
+            tx_id: tx_id.to_string(),
+            created_at,
+        });
+    }
+    pending.sort_by_key(|tx| tx.created_at);
+    Ok(pending)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    // Layout of the files exchanged with `sign-one-sided-transaction`, with the signer filling
+    // in the kernel, input and output signatures, the proofs and the offsets
+    const UNSIGNED: &str = r#"{
+        "version": "V0",
+        "tx_id": "1234",
+        "transaction": {
+            "offset": null,
+            "script_offset": null,
+            "body": {
+                "inputs": [{ "commitment": "aa01", "script_signature": null }],
+                "outputs": [
+                    { "commitment": "bb02", "features": { "output_type": 0 }, "range_proof": null, "metadata_signature": null },
+                    { "commitment": "cc03", "features": { "output_type": 0 }, "range_proof": null, "metadata_signature": null }
+                ],
+                "kernels": [{ "fee": 25, "lock_height": 0, "excess": null, "excess_sig": null }]
+            }
+        }
+    }"#;
+    const SIGNED: &str = r#"{
+        "version": "V0",
+        "tx_id": "1234",
+        "transaction": {
+            "offset": "0f",
+            "script_offset": "1e",
+            "body": {
+                "inputs": [{ "commitment": "aa01", "script_signature": { "u_a": "01", "u_x": "02" } }],
+                "outputs": [
+                    { "commitment": "bb02", "features": { "output_type": 0 }, "range_proof": "03", "metadata_signature": { "u_y": "04" } },
+                    { "commitment": "cc03", "features": { "output_type": 0 }, "range_proof": "05", "metadata_signature": { "u_y": "06" } }
+                ],
+                "kernels": [{ "fee": 25, "lock_height": 0, "excess": "07", "excess_sig": { "signature": "08" } }]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn reads_string_and_numeric_tx_ids() {
+        assert_eq!(read_tx_id(UNSIGNED).unwrap(), "1234");
+        assert_eq!(read_tx_id(SIGNED).unwrap(), "1234");
+        assert_eq!(read_tx_id(r#"{"tx_id":1234}"#).unwrap(), "1234");
+        assert!(read_tx_id(r#"{"id":1234}"#).is_err());
+        assert!(read_tx_id("not json").is_err());
+    }
+
+    #[test]
+    fn validates_signed_file_against_prepared_transaction() {
+        assert_eq!(
+            validate_signed_transaction(SIGNED, UNSIGNED).unwrap(),
+            "1234"
+        );
+        assert!(validate_signed_transaction(UNSIGNED, UNSIGNED).is_err());
+        assert!(
+            validate_signed_transaction(r#"{"tx_id":"99","signature":"abcd"}"#, UNSIGNED).is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_signed_file_with_changed_contents() {
+        let changed_fee = SIGNED.replace(r#""fee": 25"#, r#""fee": 2500"#);
+        assert!(validate_signed_transaction(&changed_fee, UNSIGNED)
+            .unwrap_err()
+            .to_string()
+            .contains("/transaction/body/kernels/0/fee"));
+
+        let changed_output = SIGNED.replace("cc03", "dd04");
+        assert!(validate_signed_transaction(&changed_output, UNSIGNED).is_err());
+
+        let extra_output = SIGNED.replace(
+            r#""outputs": ["#,
+            r#""outputs": [{ "commitment": "ee05", "range_proof": "09" },"#,
+        );
+        assert!(validate_signed_transaction(&extra_output, UNSIGNED).is_err());
+
+        let extra_field = SIGNED.replace(r#""version": "V0","#, r#""version": "V0", "memo": "x","#);
+        assert!(validate_signed_transaction(&extra_field, UNSIGNED).is_err());
+    }
+}
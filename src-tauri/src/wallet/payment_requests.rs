@@ -0,0 +1,332 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tari_common_types::tari_address::{dual_address::DualAddress, TariAddress};
+use tari_transaction_components::tari_amount::MicroMinotari;
+
+use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::internal_wallet::InternalWallet;
+use crate::utils::rand_utils;
+use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus};
+
+const LOG_TARGET: &str = "tari::universe::payment_requests";
+const PAYMENT_ID_LENGTH: usize = 12;
+const INBOUND_DIRECTION: i32 = 1;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PaymentRequestStatus {
+    Pending,
+    PartiallyPaid,
+    /// The full amount arrived but part of it is not confirmed yet
+    PendingConfirmation,
+    Paid,
+    Expired,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PaymentRequest {
+    /// Generated for every request, incoming transactions carrying it pay the request
+    pub payment_id: String,
+    pub amount: MicroMinotari,
+    pub memo: String,
+    /// Wallet address the request was created for
+    pub tari_address: String,
+    /// `tari_address` with the payment id embedded, shared with the payer
+    pub request_address: String,
+    pub created_at: u64,
+    pub expires_at: Option<u64>,
+    pub status: PaymentRequestStatus,
+    /// Received in confirmed transactions
+    pub received: MicroMinotari,
+    /// Received in transactions that are not confirmed yet, not part of `received`
+    #[serde(default)]
+    pub pending: MicroMinotari,
+    pub tx_ids: Vec<String>,
+}
+
+impl PaymentRequest {
+    pub fn new(
+        amount: MicroMinotari,
+        memo: String,
+        tari_address: &TariAddress,
+        expires_in_secs: Option<u64>,
+    ) -> Result<Self, anyhow::Error> {
+        let payment_id = rand_utils::get_rand_string(PAYMENT_ID_LENGTH);
+        let tari_address = tari_address.to_base58();
+        let request_address = encode_payment_id_to_address(&tari_address, &payment_id)?;
+        let created_at = now_secs();
+
+        Ok(Self {
+            payment_id,
+            amount,
+            memo,
+            tari_address,
+            request_address,
+            created_at,
+            expires_at: expires_in_secs.map(|secs| created_at.saturating_add(secs)),
+            status: PaymentRequestStatus::Pending,
+            received: MicroMinotari(0),
+            pending: MicroMinotari(0),
+            tx_ids: Vec::new(),
+        })
+    }
+}
+
+pub fn encode_payment_id_to_address(
+    tari_address: &str,
+    payment_id: &str,
+) -> Result<String, anyhow::Error> {
+    let mut address = DualAddress::from_base58(tari_address)
+        .map_err(|e| anyhow::anyhow!("Failed to parse Tari address: {e}"))?;
+    address
+        .add_memo_field_payment_id(payment_id.as_bytes().to_vec())
+        .map_err(|e| anyhow::anyhow!("Failed to add payment ID to Tari address: {e}"))?;
+    Ok(address.to_base58())
+}
+
+/// Recomputes what every request received from the full transaction history, returns the
+/// requests whose state changed
+pub fn reconcile_payment_requests(
+    requests: &[PaymentRequest],
+    transactions: &[TransactionInfo],
+    now: u64,
+) -> Vec<PaymentRequest> {
+    requests
+        .iter()
+        .filter_map(|request| {
+            let payments: Vec<&TransactionInfo> = transactions
+                .iter()
+                .filter(|transaction| {
+                    transaction.direction == INBOUND_DIRECTION
+                        && !transaction.is_cancelled
+                        && transaction.status != TransactionStatus::Rejected
+                        && transaction.payment_id == request.payment_id
+                })
+                .collect();
+            let sum = |confirmed: bool| {
+                MicroMinotari(
+                    payments
+                        .iter()
+                        .filter(|transaction| transaction.status.is_confirmed() == confirmed)
+                        .map(|transaction| transaction.amount.as_u64())
+                        .sum(),
+                )
+            };
+            let (received, pending) = (sum(true), sum(false));
+            let status = request_status(request, received, pending, now);
+            if received == request.received
+                && pending == request.pending
+                && status == request.status
+            {
+                return None;
+            }
+
+            Some(PaymentRequest {
+                received,
+                pending,
+                status,
+                tx_ids: payments
+                    .iter()
+                    .map(|transaction| transaction.tx_id.clone())
+                    .collect(),
+                ..request.clone()
+            })
+        })
+        .collect()
+}
+
+fn request_status(
+    request: &PaymentRequest,
+    received: MicroMinotari,
+    pending: MicroMinotari,
+    now: u64,
+) -> PaymentRequestStatus {
+    if received >= request.amount {
+        PaymentRequestStatus::Paid
+    } else if received + pending >= request.amount {
+        PaymentRequestStatus::PendingConfirmation
+    } else if received + pending > MicroMinotari(0) {
+        PaymentRequestStatus::PartiallyPaid
+    } else if request
+        .expires_at
+        .is_some_and(|expires_at| now >= expires_at)
+    {
+        PaymentRequestStatus::Expired
+    } else {
+        PaymentRequestStatus::Pending
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Matches the synced history against the requests of the selected wallet and emits the changes
+pub async fn check_payment_requests(transactions: &[TransactionInfo]) {
+    let tari_address = InternalWallet::tari_address().await.to_base58();
+    let requests: Vec<PaymentRequest> = ConfigWallet::content()
+        .await
+        .payment_requests()
+        .iter()
+        .filter(|request| request.tari_address == tari_address)
+        .cloned()
+        .collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    let changed = reconcile_payment_requests(&requests, transactions, now_secs());
+    if changed.is_empty() {
+        return;
+    }
+    if let Err(e) = ConfigWallet::update_field(
+        ConfigWalletContent::update_payment_requests,
+        changed.clone(),
+    )
+    .await
+    {
+        warn!(target: LOG_TARGET, "Failed to save payment requests: {e}");
+    }
+    for request in changed {
+        info!(target: LOG_TARGET, "Payment request {} is {:?}, received {}", request.payment_id, request.status, request.received);
+        EventsEmitter::emit_payment_request_update(request).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn request(amount: u64, expires_at: Option<u64>) -> PaymentRequest {
+        PaymentRequest {
+            payment_id: "abc123".to_string(),
+            amount: MicroMinotari(amount),
+            memo: String::new(),
+            tari_address: String::new(),
+            request_address: String::new(),
+            created_at: 0,
+            expires_at,
+            status: PaymentRequestStatus::Pending,
+            received: MicroMinotari(0),
+            pending: MicroMinotari(0),
+            tx_ids: Vec::new(),
+        }
+    }
+
+    fn payment(tx_id: &str, amount: u64, payment_id: &str) -> TransactionInfo {
+        TransactionInfo {
+            tx_id: tx_id.to_string(),
+            source_address: String::new(),
+            dest_address: String::new(),
+            status: TransactionStatus::OneSidedConfirmed,
+            amount: MicroMinotari(amount),
+            is_cancelled: false,
+            direction: INBOUND_DIRECTION,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp: 0,
+            payment_id: payment_id.to_string(),
+            mined_in_block_height: 0,
+            payment_reference: None,
+        }
+    }
+
+    #[test]
+    fn sums_matching_payments() {
+        let requests = vec![request(1_000, None)];
+
+        let changed = reconcile_payment_requests(&requests, &[payment("1", 400, "abc123")], 10);
+        let partial = changed.first().unwrap();
+        assert_eq!(partial.status, PaymentRequestStatus::PartiallyPaid);
+        assert_eq!(partial.received, MicroMinotari(400));
+
+        let transactions = vec![
+            payment("1", 400, "abc123"),
+            payment("2", 600, "abc123"),
+            payment("3", 5_000, "other"),
+        ];
+        let changed = reconcile_payment_requests(&[partial.clone()], &transactions, 10);
+        let paid = changed.first().unwrap();
+        assert_eq!(paid.status, PaymentRequestStatus::Paid);
+        assert_eq!(paid.tx_ids, vec!["1".to_string(), "2".to_string()]);
+    }
+
+    #[test]
+    fn unconfirmed_payments_only_count_as_pending() {
+        let requests = vec![request(1_000, None)];
+        let mut unconfirmed = payment("1", 1_000, "abc123");
+        unconfirmed.status = TransactionStatus::OneSidedUnconfirmed;
+
+        let changed = reconcile_payment_requests(&requests, &[unconfirmed.clone()], 10);
+        let pending = changed.first().unwrap();
+        assert_eq!(pending.status, PaymentRequestStatus::PendingConfirmation);
+        assert_eq!(pending.received, MicroMinotari(0));
+        assert_eq!(pending.pending, MicroMinotari(1_000));
+
+        unconfirmed.amount = MicroMinotari(400);
+        let changed = reconcile_payment_requests(&requests, &[unconfirmed], 10);
+        assert_eq!(
+            changed.first().unwrap().status,
+            PaymentRequestStatus::PartiallyPaid
+        );
+
+        let changed =
+            reconcile_payment_requests(&[pending.clone()], &[payment("1", 1_000, "abc123")], 10);
+        let paid = changed.first().unwrap();
+        assert_eq!(paid.status, PaymentRequestStatus::Paid);
+        assert_eq!(paid.pending, MicroMinotari(0));
+    }
+
+    #[test]
+    fn ignores_outbound_and_cancelled_transactions() {
+        let mut outbound = payment("1", 1_000, "abc123");
+        outbound.direction = 2;
+        let mut cancelled = payment("2", 1_000, "abc123");
+        cancelled.is_cancelled = true;
+
+        let changed =
+            reconcile_payment_requests(&[request(1_000, None)], &[outbound, cancelled], 10);
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn unpaid_request_expires() {
+        let requests = vec![request(1_000, Some(100))];
+
+        assert!(reconcile_payment_requests(&requests, &[], 99).is_empty());
+        let changed = reconcile_payment_requests(&requests, &[], 100);
+        assert_eq!(
+            changed.first().unwrap().status,
+            PaymentRequestStatus::Expired
+        );
+    }
+}
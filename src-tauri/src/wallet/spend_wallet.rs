@@ -26,10 +26,11 @@ use std::time::Duration;
 
 use anyhow::{Context, Error, Result};
 use axum::async_trait;
-use log::{debug, info};
+use log::{debug, info, warn};
 use tari_common::configuration::Network;
 use tari_common_types::seeds::mnemonic::{Mnemonic, MnemonicLanguage};
 use tari_shutdown::Shutdown;
+use tari_utilities::SafePassword;
 use tauri::{AppHandle, Manager};
 
 use crate::binaries::{Binaries, BinaryResolver};
@@ -85,6 +86,7 @@ impl SpendWallet {
     /// # Arguments
     /// * `input_file` - Path to the input transaction file to be signed
     /// * `output_file` - Path where the signed transaction will be written
+    /// * `pin_password` - Validated PIN, required when the wallet is PIN locked
     /// * `app_handle` - Tauri AppHandle for accessing application paths
     ///
     /// # Returns
@@ -93,16 +95,77 @@ impl SpendWallet {
         &self,
         input_file: PathBuf,
         output_file: PathBuf,
+        pin_password: Option<SafePassword>,
         app_handle: &AppHandle,
     ) -> Result<(), Error> {
-        let seed_words = self
-            .get_seed_words(app_handle)
+        let seed_words = Self::seed_words_for_pin(pin_password)
             .await
             .context("Failed to retrieve wallet seed words")?;
 
         // Required step
         self.sync_wallet(app_handle, &seed_words).await?;
 
+        self.sign(input_file, output_file, app_handle, &seed_words)
+            .await
+    }
+
+    /// Signs a one-sided transaction exported from another machine. The signer is expected to be
+    /// air-gapped, so a failed sync is logged and signing is attempted anyway: the unsigned file
+    /// carries the inputs being spent and the keys come from the seed words, the sync only
+    /// prepares the local wallet database. When signing fails after a failed sync the error says
+    /// so, and a signer that never produces the output file is reported as a failure.
+    ///
+    /// # Arguments
+    /// * `input_file` - Path to the unsigned transaction file exported by the online machine
+    /// * `output_file` - Path where the signed transaction will be written
+    /// * `app_handle` - Tauri AppHandle for accessing application paths
+    ///
+    /// # Returns
+    /// * `Result<(), Error>` - Ok if the transaction was successfully signed, otherwise an error
+    pub async fn sign_offline_transaction(
+        &self,
+        input_file: PathBuf,
+        output_file: PathBuf,
+        app_handle: &AppHandle,
+    ) -> Result<(), Error> {
+        let seed_words = self
+            .get_seed_words(app_handle)
+            .await
+            .context("Failed to retrieve wallet seed words")?;
+
+        let sync_result = self.sync_wallet(app_handle, &seed_words).await;
+        if let Err(e) = &sync_result {
+            warn!(target: LOG_TARGET, "Spend Wallet sync failed on the offline signer: {e}");
+        }
+
+        let sign_result = self
+            .sign(input_file, output_file.clone(), app_handle, &seed_words)
+            .await
+            .and_then(|()| {
+                if output_file.exists() {
+                    Ok(())
+                } else {
+                    Err(anyhow::anyhow!(
+                        "Signer finished without writing {}",
+                        output_file.display()
+                    ))
+                }
+            });
+        match (sign_result, sync_result) {
+            (Err(e), Err(sync_error)) => Err(anyhow::anyhow!(
+                "Failed to sign the transaction without syncing the wallet first, connect the signer to the network once and retry: {e} (sync error: {sync_error})"
+            )),
+            (sign_result, _) => sign_result,
+        }
+    }
+
+    async fn sign(
+        &self,
+        input_file: PathBuf,
+        output_file: PathBuf,
+        app_handle: &AppHandle,
+        seed_words: &str,
+    ) -> Result<(), Error> {
         let sign_command = CommandBuilder::new("sign-one-sided-transaction")
             .add_args(&[
                 "--skip-recovery",
@@ -112,7 +175,7 @@ impl SpendWallet {
                 "--output-file",
                 &output_file.to_string_lossy(),
             ])
-            .add_env("MINOTARI_WALLET_SEED_WORDS", &seed_words);
+            .add_env("MINOTARI_WALLET_SEED_WORDS", seed_words);
 
         let (exit_code, _stdout, _stderr) = self
             .execute_command(app_handle, sign_command, vec![EXIT_CODE_ZERO])
@@ -236,6 +299,10 @@ impl SpendWallet {
         let pin_password = PinManager::get_validated_pin_if_defined(app_handle)
             .await
             .context("Failed to validate PIN")?;
+        Self::seed_words_for_pin(pin_password).await
+    }
+
+    async fn seed_words_for_pin(pin_password: Option<SafePassword>) -> Result<String, Error> {
         let tari_cipher_seed = InternalWallet::get_tari_seed(pin_password)
             .await
             .context("Failed to get Tari seed")?;
@@ -0,0 +1,144 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use anyhow::anyhow;
+
+use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus};
+
+const OUTBOUND_DIRECTION: i32 = 2;
+
+/// Outgoing transactions can be cancelled, releasing their inputs, until they are signed. A signed
+/// transaction may already be in a mempool and get mined after its inputs were spent elsewhere.
+pub fn ensure_cancellable(transaction: &TransactionInfo) -> Result<(), anyhow::Error> {
+    ensure_unmined_outbound(transaction, "cancelled")?;
+    match transaction.status {
+        TransactionStatus::Pending | TransactionStatus::Queued => Ok(()),
+        status => Err(anyhow!(
+            "Transaction {} can't be cancelled in status {status:?}",
+            transaction.tx_id
+        )),
+    }
+}
+
+/// Signed outgoing transactions that did not make it into a block can be sent to the mempool again
+pub fn ensure_rebroadcastable(transaction: &TransactionInfo) -> Result<(), anyhow::Error> {
+    ensure_unmined_outbound(transaction, "rebroadcast")?;
+    match transaction.status {
+        TransactionStatus::Completed | TransactionStatus::Broadcast => Ok(()),
+        TransactionStatus::Rejected => Err(anyhow!(
+            "Transaction {} was rejected by the mempool and can't be rebroadcast",
+            transaction.tx_id
+        )),
+        TransactionStatus::Pending | TransactionStatus::Queued => Err(anyhow!(
+            "Transaction {} has not been signed yet",
+            transaction.tx_id
+        )),
+        status => Err(anyhow!(
+            "Transaction {} can't be rebroadcast in status {status:?}",
+            transaction.tx_id
+        )),
+    }
+}
+
+fn ensure_unmined_outbound(
+    transaction: &TransactionInfo,
+    action: &str,
+) -> Result<(), anyhow::Error> {
+    if transaction.direction != OUTBOUND_DIRECTION {
+        return Err(anyhow!(
+            "Transaction {} is not outgoing and can't be {action}",
+            transaction.tx_id
+        ));
+    }
+    if transaction.is_cancelled {
+        return Err(anyhow!(
+            "Transaction {} is already cancelled",
+            transaction.tx_id
+        ));
+    }
+    let is_mined = matches!(
+        transaction.status,
+        TransactionStatus::MinedUnconfirmed | TransactionStatus::MinedConfirmed
+    ) || transaction.mined_in_block_height > 0;
+    if is_mined {
+        return Err(anyhow!(
+            "Transaction {} is already mined and can't be {action}",
+            transaction.tx_id
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    fn outbound(status: TransactionStatus) -> TransactionInfo {
+        TransactionInfo {
+            tx_id: "1".to_string(),
+            source_address: String::new(),
+            dest_address: String::new(),
+            status,
+            amount: MicroMinotari(1_000),
+            is_cancelled: false,
+            direction: OUTBOUND_DIRECTION,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp: 0,
+            payment_id: String::new(),
+            mined_in_block_height: 0,
+            payment_reference: None,
+        }
+    }
+
+    #[test]
+    fn only_unsigned_outbound_transactions_can_be_cancelled() {
+        assert!(ensure_cancellable(&outbound(TransactionStatus::Pending)).is_ok());
+        assert!(ensure_cancellable(&outbound(TransactionStatus::Queued)).is_ok());
+        assert!(ensure_cancellable(&outbound(TransactionStatus::Completed)).is_err());
+        assert!(ensure_cancellable(&outbound(TransactionStatus::Broadcast)).is_err());
+        assert!(ensure_cancellable(&outbound(TransactionStatus::Rejected)).is_err());
+        assert!(ensure_cancellable(&outbound(TransactionStatus::MinedUnconfirmed)).is_err());
+
+        let mut inbound = outbound(TransactionStatus::Pending);
+        inbound.direction = 1;
+        assert!(ensure_cancellable(&inbound).is_err());
+
+        let mut cancelled = outbound(TransactionStatus::Pending);
+        cancelled.is_cancelled = true;
+        assert!(ensure_cancellable(&cancelled).is_err());
+    }
+
+    #[test]
+    fn only_signed_unmined_transactions_can_be_rebroadcast() {
+        assert!(ensure_rebroadcastable(&outbound(TransactionStatus::Broadcast)).is_ok());
+        assert!(ensure_rebroadcastable(&outbound(TransactionStatus::Completed)).is_ok());
+        assert!(ensure_rebroadcastable(&outbound(TransactionStatus::Pending)).is_err());
+        assert!(ensure_rebroadcastable(&outbound(TransactionStatus::Rejected)).is_err());
+
+        let mut mined = outbound(TransactionStatus::Broadcast);
+        mined.mined_in_block_height = 100;
+        assert!(ensure_rebroadcastable(&mined).is_err());
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::Path;
+
+use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::utils::csv_utils::to_csv_line;
+use crate::wallet::wallet_types::TransactionInfo;
+
+const CSV_HEADER: [&str; 14] = [
+    "tx_id",
+    "date",
+    "timestamp",
+    "direction",
+    "status",
+    "amount",
+    "fee",
+    "is_coinbase",
+    "is_cancelled",
+    "payment_id",
+    "block_height",
+    "payment_reference",
+    "source_address",
+    "dest_address",
+];
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionExportFormat {
+    Csv,
+    Json,
+}
+
+/// One exported transaction, amounts are in XTM with all six decimals so spreadsheets do not
+/// need to know about microMinotari
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionExportRecord {
+    pub tx_id: String,
+    pub date: String,
+    pub timestamp: u64,
+    pub direction: &'static str,
+    pub status: String,
+    pub amount: String,
+    pub fee: String,
+    pub is_coinbase: bool,
+    pub is_cancelled: bool,
+    pub payment_id: String,
+    pub block_height: Option<u64>,
+    pub payment_reference: Option<String>,
+    pub source_address: String,
+    pub dest_address: String,
+}
+
+impl TransactionExportRecord {
+    pub fn new(transaction: &TransactionInfo, time_zone: ExportTimeZone) -> Self {
+        let timestamp = i64::try_from(transaction.timestamp).unwrap_or(i64::MAX);
+        let date = Utc
+            .timestamp_opt(timestamp, 0)
+            .single()
+            .map(|date| time_zone.format(date))
+            .unwrap_or_default();
+
+        Self {
+            tx_id: transaction.tx_id.clone(),
+            date,
+            timestamp: transaction.timestamp,
+            direction: match transaction.direction {
+                1 => "inbound",
+                2 => "outbound",
+                _ => "unknown",
+            },
+            status: format!("{:?}", transaction.status),
+            amount: format_xtm(transaction.amount.as_u64()),
+            fee: format_xtm(transaction.fee),
+            is_coinbase: transaction.status.is_coinbase(),
+            is_cancelled: transaction.is_cancelled,
+            payment_id: transaction.payment_id.clone(),
+            block_height: (transaction.mined_in_block_height > 0)
+                .then_some(transaction.mined_in_block_height),
+            payment_reference: transaction.payment_reference.clone(),
+            source_address: transaction.source_address.clone(),
+            dest_address: transaction.dest_address.clone(),
+        }
+    }
+
+    fn csv_fields(&self) -> [String; 14] {
+        [
+            self.tx_id.clone(),
+            self.date.clone(),
+            self.timestamp.to_string(),
+            self.direction.to_string(),
+            self.status.clone(),
+            self.amount.clone(),
+            self.fee.clone(),
+            self.is_coinbase.to_string(),
+            self.is_cancelled.to_string(),
+            self.payment_id.clone(),
+            self.block_height.map(|h| h.to_string()).unwrap_or_default(),
+            self.payment_reference.clone().unwrap_or_default(),
+            self.source_address.clone(),
+            self.dest_address.clone(),
+        ]
+    }
+}
+
+/// Zone the export dates are written in. The local zone of the system applies the daylight
+/// saving offset of each transaction's own date, a fixed offset uses the same one for all of them.
+#[derive(Debug, Clone, Copy)]
+pub enum ExportTimeZone {
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl ExportTimeZone {
+    /// Prefers the system's local zone over the offset in minutes
+    pub fn parse(
+        use_local_time_zone: bool,
+        utc_offset_minutes: Option<i32>,
+    ) -> Result<Self, anyhow::Error> {
+        if use_local_time_zone {
+            return Ok(Self::Local);
+        }
+        utc_offset_from_minutes(utc_offset_minutes).map(Self::Fixed)
+    }
+
+    fn format(&self, date: DateTime<Utc>) -> String {
+        match self {
+            Self::Local => date.with_timezone(&Local).to_rfc3339(),
+            Self::Fixed(offset) => date.with_timezone(offset).to_rfc3339(),
+        }
+    }
+}
+
+/// Parses an offset in minutes east of UTC, as sent by the frontend
+pub fn utc_offset_from_minutes(minutes: Option<i32>) -> Result<FixedOffset, anyhow::Error> {
+    let minutes = minutes.unwrap_or(0);
+    minutes
+        .checked_mul(60)
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| anyhow::anyhow!("Invalid UTC offset: {minutes} minutes"))
+}
+
+pub fn to_csv(records: &[TransactionExportRecord]) -> String {
+    let mut csv = to_csv_line(&CSV_HEADER);
+    csv.push('\n');
+    for record in records {
+        csv.push_str(&to_csv_line(&record.csv_fields()));
+        csv.push('\n');
+    }
+    csv
+}
+
+pub async fn write_export(
+    path: &Path,
+    format: TransactionExportFormat,
+    records: &[TransactionExportRecord],
+) -> Result<(), anyhow::Error> {
+    let contents = match format {
+        TransactionExportFormat::Csv => to_csv(records),
+        TransactionExportFormat::Json => serde_json::to_string_pretty(records)?,
+    };
+    fs::write(path, contents).await?;
+    Ok(())
+}
+
+fn format_xtm(micro_minotari: u64) -> String {
+    format!(
+        "{}.{:06}",
+        micro_minotari / 1_000_000,
+        micro_minotari % 1_000_000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::wallet::wallet_types::TransactionStatus;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    fn coinbase_transaction() -> TransactionInfo {
+        TransactionInfo {
+            tx_id: "42".to_string(),
+            source_address: "source".to_string(),
+            dest_address: "dest".to_string(),
+            status: TransactionStatus::CoinbaseConfirmed,
+            amount: MicroMinotari(12_345_678),
+            is_cancelled: false,
+            direction: 1,
+            excess_sig: vec![],
+            fee: 25,
+            timestamp: 1_700_000_000,
+            payment_id: "reward, \"block\"".to_string(),
+            mined_in_block_height: 1000,
+            payment_reference: None,
+        }
+    }
+
+    #[test]
+    fn record_uses_offset_and_xtm_amounts() {
+        let time_zone = ExportTimeZone::parse(false, Some(120)).unwrap();
+        let record = TransactionExportRecord::new(&coinbase_transaction(), time_zone);
+
+        assert_eq!(record.date, "2023-11-15T00:13:20+02:00");
+        assert_eq!(record.amount, "12.345678");
+        assert_eq!(record.fee, "0.000025");
+        assert_eq!(record.direction, "inbound");
+        assert!(record.is_coinbase);
+        assert_eq!(record.block_height, Some(1000));
+    }
+
+    #[test]
+    fn csv_escapes_fields() {
+        let record = TransactionExportRecord::new(
+            &coinbase_transaction(),
+            ExportTimeZone::parse(false, None).unwrap(),
+        );
+        let csv = to_csv(&[record]);
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next().unwrap(), CSV_HEADER.join(","));
+        assert!(lines
+            .next()
+            .unwrap()
+            .contains(",\"reward, \"\"block\"\"\",1000,,"));
+    }
+
+    #[test]
+    fn local_zone_keeps_the_instant() {
+        let time_zone = ExportTimeZone::parse(true, Some(120)).unwrap();
+        let record = TransactionExportRecord::new(&coinbase_transaction(), time_zone);
+
+        let date = DateTime::parse_from_rfc3339(&record.date).unwrap();
+        assert_eq!(date.timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn rejects_out_of_range_offset() {
+        assert!(utc_offset_from_minutes(Some(24 * 60)).is_err());
+        assert!(utc_offset_from_minutes(Some(-5 * 60 - 30)).is_ok());
+    }
+}
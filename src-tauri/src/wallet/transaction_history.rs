@@ -0,0 +1,508 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+use tari_transaction_components::tari_amount::MicroMinotari;
+use tokio::sync::RwLock;
+
+use crate::wallet::transaction_search::TransactionSearch;
+use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus};
+
+/// Payment references are only reported once a transaction is this deep in the chain
+pub const PAYMENT_REFERENCE_CONFIRMATIONS: u64 = 5;
+// Catching up block by block is slower than one full listing past this point
+const MAX_INCREMENTAL_SYNC_BLOCKS: u64 = 100;
+
+static INSTANCE: LazyLock<RwLock<TransactionHistoryCache>> =
+    LazyLock::new(|| RwLock::new(TransactionHistoryCache::default()));
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TransactionHistoryFilter {
+    /// Same encoding as the wallet's status bitflag, `1 << TransactionStatus`
+    pub status_bitflag: Option<u32>,
+    pub direction: Option<i32>,
+    pub from_timestamp: Option<u64>,
+    pub to_timestamp: Option<u64>,
+    pub min_amount: Option<MicroMinotari>,
+    pub max_amount: Option<MicroMinotari>,
+}
+
+impl TransactionHistoryFilter {
+    pub fn with_status_bitflag(status_bitflag: Option<u32>) -> Self {
+        Self {
+            status_bitflag,
+            ..Default::default()
+        }
+    }
+
+    pub fn matches(&self, transaction: &TransactionInfo) -> bool {
+        self.status_bitflag
+            .is_none_or(|bitflag| bitflag == 0 || bitflag & (1 << transaction.status as u32) != 0)
+            && self
+                .direction
+                .is_none_or(|direction| transaction.direction == direction)
+            && self
+                .from_timestamp
+                .is_none_or(|from| transaction.timestamp >= from)
+            && self
+                .to_timestamp
+                .is_none_or(|to| transaction.timestamp <= to)
+            && self.min_amount.is_none_or(|min| transaction.amount >= min)
+            && self.max_amount.is_none_or(|max| transaction.amount <= max)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TransactionHistoryUpdatePayload {
+    pub added: Vec<TransactionInfo>,
+    pub updated: Vec<TransactionInfo>,
+    pub removed: Vec<String>,
+}
+
+impl TransactionHistoryUpdatePayload {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Local copy of the wallet's completed transactions, kept in sync from the streaming API so
+/// history pages and filters are served without going back to the wallet
+///
+/// After the first full listing the cache only pulls the blocks the wallet scanned since the
+/// last sync and looks up the transactions that can still change by ID.
+#[derive(Debug, Default)]
+pub struct TransactionHistoryCache {
+    transactions: HashMap<String, TransactionInfo>,
+    synced_block_height: Option<u64>,
+    // Wallet scanned height the cache holds every mined transaction up to
+    scanned_height_cursor: Option<u64>,
+    // Sent since the last sync, not mined so not found by the scanned height cursor
+    tracked_tx_ids: HashSet<String>,
+}
+
+impl TransactionHistoryCache {
+    pub fn current() -> &'static RwLock<TransactionHistoryCache> {
+        &INSTANCE
+    }
+
+    pub fn synced_block_height(&self) -> Option<u64> {
+        self.synced_block_height
+    }
+
+    /// Blocks to pull for an incremental sync up to the wallet's `scanned_height`, `None` when a
+    /// full listing is needed because nothing is cached yet, the wallet rescanned or it is too
+    /// far ahead
+    pub fn incremental_sync_range(&self, scanned_height: u64) -> Option<RangeInclusive<u64>> {
+        let cursor = self.scanned_height_cursor?;
+        if scanned_height < cursor || scanned_height - cursor > MAX_INCREMENTAL_SYNC_BLOCKS {
+            return None;
+        }
+        Some(cursor + 1..=scanned_height)
+    }
+
+    /// Transactions whose status or payment reference can still change at `block_height`
+    pub fn refresh_tx_ids(&self, block_height: u64) -> Vec<String> {
+        self.transactions
+            .values()
+            .filter(|transaction| !is_final(transaction, block_height))
+            .map(|transaction| transaction.tx_id.clone())
+            .chain(self.tracked_tx_ids.iter().cloned())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Picks up a transaction sent by this app on the next incremental sync
+    pub fn track(&mut self, tx_id: String) {
+        self.tracked_tx_ids.insert(tx_id);
+    }
+
+    /// Replaces the cached history with a full listing from the wallet and returns what changed
+    pub fn apply(
+        &mut self,
+        transactions: Vec<TransactionInfo>,
+        block_height: u64,
+        scanned_height: Option<u64>,
+    ) -> TransactionHistoryUpdatePayload {
+        let mut update = TransactionHistoryUpdatePayload::default();
+        let mut previous = std::mem::take(&mut self.transactions);
+
+        for transaction in transactions {
+            match previous.remove(&transaction.tx_id) {
+                None => update.added.push(transaction.clone()),
+                Some(cached) if cached != transaction => update.updated.push(transaction.clone()),
+                Some(_) => {}
+            }
+            self.transactions
+                .insert(transaction.tx_id.clone(), transaction);
+        }
+        update.removed = previous.into_keys().collect();
+        self.synced_block_height = Some(block_height);
+        self.scanned_height_cursor = scanned_height;
+        self.tracked_tx_ids.clear();
+
+        update
+    }
+
+    /// Merges the transactions mined since the cursor and the refreshed `refresh_tx_ids`, which
+    /// are removed when the wallet no longer knows them, and returns what changed
+    pub fn apply_incremental(
+        &mut self,
+        refresh_tx_ids: &[String],
+        transactions: Vec<TransactionInfo>,
+        block_height: u64,
+        scanned_height: u64,
+    ) -> TransactionHistoryUpdatePayload {
+        let mut update = TransactionHistoryUpdatePayload::default();
+        let transactions: HashMap<String, TransactionInfo> = transactions
+            .into_iter()
+            .map(|transaction| (transaction.tx_id.clone(), transaction))
+            .collect();
+
+        for tx_id in refresh_tx_ids {
+            if !transactions.contains_key(tx_id) && self.transactions.remove(tx_id).is_some() {
+                update.removed.push(tx_id.clone());
+            }
+        }
+        for (tx_id, transaction) in transactions {
+            match self.transactions.get(&tx_id) {
+                None => update.added.push(transaction.clone()),
+                Some(cached) if *cached != transaction => update.updated.push(transaction.clone()),
+                Some(_) => {}
+            }
+            self.transactions.insert(tx_id, transaction);
+        }
+        self.synced_block_height = Some(block_height);
+        self.scanned_height_cursor = Some(scanned_height);
+        self.tracked_tx_ids.clear();
+
+        update
+    }
+
+    pub fn get(&self, tx_id: &str) -> Option<&TransactionInfo> {
+        self.transactions.get(tx_id)
+    }
+
+    /// Coinbase this wallet received for the block mined at `block_height`, if any
+    pub fn find_coinbase_mined_at(&self, block_height: u64) -> Option<&TransactionInfo> {
+        self.coinbases()
+            .find(|transaction| transaction.mined_in_block_height == block_height)
+    }
+
+    /// Heights of all the blocks this wallet received a coinbase for
+    pub fn won_block_heights(&self) -> HashSet<u64> {
+        self.coinbases()
+            .map(|transaction| transaction.mined_in_block_height)
+            .collect()
+    }
+
+    fn coinbases(&self) -> impl Iterator<Item = &TransactionInfo> {
+        self.transactions
+            .values()
+            .filter(|transaction| transaction.status.is_coinbase() && !transaction.is_cancelled)
+    }
+
+    /// Newest first, matching the order the wallet returns its history in
+    pub fn query(
+        &self,
+        filter: &TransactionHistoryFilter,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<TransactionInfo> {
+        self.select(|transaction| filter.matches(transaction), offset, limit)
+    }
+
+    pub fn search(
+        &self,
+        search: &TransactionSearch,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<TransactionInfo> {
+        self.select(|transaction| search.matches(transaction), offset, limit)
+    }
+
+    fn select(
+        &self,
+        predicate: impl Fn(&TransactionInfo) -> bool,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<TransactionInfo> {
+        let mut transactions: Vec<&TransactionInfo> = self
+            .transactions
+            .values()
+            .filter(|transaction| predicate(transaction))
+            .collect();
+        transactions.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| b.tx_id.cmp(&a.tx_id))
+        });
+
+        let limit = match limit {
+            Some(0) | None => usize::MAX,
+            Some(limit) => limit as usize,
+        };
+        transactions
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.transactions.clear();
+        self.synced_block_height = None;
+        self.scanned_height_cursor = None;
+        self.tracked_tx_ids.clear();
+    }
+}
+
+fn is_final(transaction: &TransactionInfo, block_height: u64) -> bool {
+    if transaction.is_cancelled {
+        return true;
+    }
+    let settled =
+        transaction.status.is_confirmed() || transaction.status == TransactionStatus::Rejected;
+    settled
+        && (transaction.payment_reference.is_some()
+            || block_height >= transaction.mined_in_block_height + PAYMENT_REFERENCE_CONFIRMATIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn transaction(
+        tx_id: &str,
+        timestamp: u64,
+        amount: u64,
+        status: TransactionStatus,
+    ) -> TransactionInfo {
+        TransactionInfo {
+            tx_id: tx_id.to_string(),
+            source_address: String::new(),
+            dest_address: String::new(),
+            status,
+            amount: MicroMinotari(amount),
+            is_cancelled: false,
+            direction: 1,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp,
+            payment_id: String::new(),
+            mined_in_block_height: 0,
+            payment_reference: None,
+        }
+    }
+
+    #[test]
+    fn find_coinbase_mined_at_looks_past_recent_coinbases() {
+        let mut cache = TransactionHistoryCache::default();
+        let mut transactions: Vec<TransactionInfo> = (0..20)
+            .map(|i| {
+                let mut coinbase = transaction(
+                    &i.to_string(),
+                    100 - i,
+                    100,
+                    TransactionStatus::CoinbaseConfirmed,
+                );
+                coinbase.mined_in_block_height = 1_000 - i;
+                coinbase
+            })
+            .collect();
+        let mut transfer = transaction("transfer", 50, 100, TransactionStatus::MinedConfirmed);
+        transfer.mined_in_block_height = 500;
+        transactions.push(transfer);
+        cache.apply(transactions, 1_000, None);
+
+        assert_eq!(
+            cache
+                .find_coinbase_mined_at(981)
+                .map(|tx| tx.tx_id.as_str()),
+            Some("19")
+        );
+        assert!(cache.find_coinbase_mined_at(500).is_none());
+        assert!(cache.find_coinbase_mined_at(1_001).is_none());
+        let won_heights = cache.won_block_heights();
+        assert_eq!(won_heights.len(), 20);
+        assert!(won_heights.contains(&981) && !won_heights.contains(&500));
+    }
+
+    #[test]
+    fn apply_reports_added_updated_and_removed() {
+        let mut cache = TransactionHistoryCache::default();
+        let update = cache.apply(
+            vec![
+                transaction("1", 10, 100, TransactionStatus::Broadcast),
+                transaction("2", 20, 200, TransactionStatus::MinedConfirmed),
+            ],
+            5,
+            None,
+        );
+        assert_eq!(update.added.len(), 2);
+
+        let update = cache.apply(
+            vec![
+                transaction("1", 10, 100, TransactionStatus::MinedConfirmed),
+                transaction("3", 30, 300, TransactionStatus::Completed),
+            ],
+            6,
+            None,
+        );
+        assert_eq!(update.added.first().unwrap().tx_id, "3");
+        assert_eq!(update.updated.first().unwrap().tx_id, "1");
+        assert_eq!(update.removed, vec!["2".to_string()]);
+        assert_eq!(cache.synced_block_height(), Some(6));
+
+        assert!(cache
+            .apply(
+                vec![
+                    transaction("1", 10, 100, TransactionStatus::MinedConfirmed),
+                    transaction("3", 30, 300, TransactionStatus::Completed),
+                ],
+                7,
+                None,
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn query_filters_and_paginates_newest_first() {
+        let mut cache = TransactionHistoryCache::default();
+        cache.apply(
+            (1..=5)
+                .map(|i| {
+                    transaction(
+                        &i.to_string(),
+                        i * 10,
+                        i * 100,
+                        TransactionStatus::MinedConfirmed,
+                    )
+                })
+                .collect(),
+            1,
+            None,
+        );
+
+        let page = cache.query(&TransactionHistoryFilter::default(), Some(1), Some(2));
+        let ids: Vec<&str> = page.iter().map(|tx| tx.tx_id.as_str()).collect();
+        assert_eq!(ids, vec!["4", "3"]);
+
+        let filter = TransactionHistoryFilter {
+            from_timestamp: Some(20),
+            max_amount: Some(MicroMinotari(400)),
+            ..Default::default()
+        };
+        let ids: Vec<String> = cache
+            .query(&filter, None, None)
+            .into_iter()
+            .map(|tx| tx.tx_id)
+            .collect();
+        assert_eq!(ids, vec!["4", "3", "2"]);
+    }
+
+    #[test]
+    fn incremental_sync_needs_a_cursor_close_to_the_scanned_height() {
+        let mut cache = TransactionHistoryCache::default();
+        assert_eq!(cache.incremental_sync_range(10), None);
+
+        cache.apply(vec![], 10, Some(10));
+        assert!(cache.incremental_sync_range(10).unwrap().is_empty());
+        assert_eq!(cache.incremental_sync_range(12), Some(11..=12));
+        assert_eq!(cache.incremental_sync_range(9), None);
+        assert_eq!(
+            cache.incremental_sync_range(10 + MAX_INCREMENTAL_SYNC_BLOCKS + 1),
+            None
+        );
+    }
+
+    #[test]
+    fn refreshes_only_transactions_that_can_change() {
+        let mut cache = TransactionHistoryCache::default();
+        let mut mined = transaction("1", 10, 100, TransactionStatus::MinedConfirmed);
+        mined.mined_in_block_height = 100;
+        let mut recently_mined = transaction("2", 20, 200, TransactionStatus::MinedConfirmed);
+        recently_mined.mined_in_block_height = 103;
+        let broadcast = transaction("3", 30, 300, TransactionStatus::Broadcast);
+        let mut cancelled = transaction("4", 40, 400, TransactionStatus::Completed);
+        cancelled.is_cancelled = true;
+        cache.apply(
+            vec![mined, recently_mined, broadcast, cancelled],
+            106,
+            Some(106),
+        );
+        cache.track("5".to_string());
+
+        let mut tx_ids = cache.refresh_tx_ids(106);
+        tx_ids.sort();
+        assert_eq!(tx_ids, vec!["2", "3", "5"]);
+    }
+
+    #[test]
+    fn apply_incremental_merges_new_and_refreshed_transactions() {
+        let mut cache = TransactionHistoryCache::default();
+        cache.apply(
+            vec![
+                transaction("1", 10, 100, TransactionStatus::MinedConfirmed),
+                transaction("2", 20, 200, TransactionStatus::Broadcast),
+                transaction("3", 30, 300, TransactionStatus::Completed),
+            ],
+            5,
+            Some(5),
+        );
+
+        let update = cache.apply_incremental(
+            &["2".to_string(), "3".to_string()],
+            vec![
+                transaction("2", 20, 200, TransactionStatus::MinedUnconfirmed),
+                transaction("4", 40, 400, TransactionStatus::OneSidedUnconfirmed),
+            ],
+            6,
+            6,
+        );
+        assert_eq!(update.added.first().unwrap().tx_id, "4");
+        assert_eq!(update.updated.first().unwrap().tx_id, "2");
+        assert_eq!(update.removed, vec!["3".to_string()]);
+        assert!(cache.get("1").is_some());
+        assert!(cache.incremental_sync_range(6).unwrap().is_empty());
+    }
+
+    #[test]
+    fn status_bitflag_filter() {
+        let coinbase = transaction("1", 10, 100, TransactionStatus::CoinbaseConfirmed);
+        let transfer = transaction("2", 20, 200, TransactionStatus::MinedConfirmed);
+        let filter = TransactionHistoryFilter::with_status_bitflag(Some(
+            1 << TransactionStatus::CoinbaseConfirmed as u32,
+        ));
+
+        assert!(filter.matches(&coinbase));
+        assert!(!filter.matches(&transfer));
+        assert!(TransactionHistoryFilter::with_status_bitflag(Some(0)).matches(&transfer));
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+use tari_common_types::tari_address::TariAddress;
+use tari_common_types::types::CompressedPublicKey;
+
+use crate::wallet::transaction_history::TransactionHistoryFilter;
+use crate::wallet::wallet_types::TransactionInfo;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TransactionSearchQuery {
+    /// Every word has to appear in the memo, case insensitive
+    pub memo: Option<String>,
+    /// Exact match on the payment id
+    pub payment_id: Option<String>,
+    /// Emoji, base58 or hex, matches either the sender or the recipient
+    pub address: Option<String>,
+    /// Status, direction, time and amount ranges
+    #[serde(flatten)]
+    pub filter: TransactionHistoryFilter,
+}
+
+/// Search query with the memo split into words and the address parsed once
+#[derive(Debug, Clone)]
+pub struct TransactionSearch {
+    memo_terms: Vec<String>,
+    payment_id: Option<String>,
+    // Addresses of one wallet differ between formats, features and embedded payment ids, the
+    // spend key stays the same
+    address_spend_key: Option<CompressedPublicKey>,
+    filter: TransactionHistoryFilter,
+}
+
+impl TryFrom<TransactionSearchQuery> for TransactionSearch {
+    type Error = anyhow::Error;
+
+    fn try_from(query: TransactionSearchQuery) -> Result<Self, Self::Error> {
+        let address_spend_key = query
+            .address
+            .as_deref()
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(|address| {
+                TariAddress::from_str(address)
+                    .map(|address| address.public_spend_key().clone())
+                    .map_err(|_| anyhow!("Invalid Tari address: {address}"))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            memo_terms: query
+                .memo
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(str::to_lowercase)
+                .collect(),
+            payment_id: query.payment_id.filter(|payment_id| !payment_id.is_empty()),
+            address_spend_key,
+            filter: query.filter,
+        })
+    }
+}
+
+impl TransactionSearch {
+    pub fn matches(&self, transaction: &TransactionInfo) -> bool {
+        self.filter.matches(transaction)
+            && self
+                .payment_id
+                .as_ref()
+                .is_none_or(|payment_id| &transaction.payment_id == payment_id)
+            && self.matches_memo(transaction)
+            && self.matches_address(transaction)
+    }
+
+    fn matches_memo(&self, transaction: &TransactionInfo) -> bool {
+        if self.memo_terms.is_empty() {
+            return true;
+        }
+        let memo = transaction.payment_id.to_lowercase();
+        self.memo_terms.iter().all(|term| memo.contains(term))
+    }
+
+    fn matches_address(&self, transaction: &TransactionInfo) -> bool {
+        let Some(spend_key) = &self.address_spend_key else {
+            return true;
+        };
+        [&transaction.source_address, &transaction.dest_address]
+            .into_iter()
+            .filter_map(|address| TariAddress::from_base58(address).ok())
+            .any(|address| address.public_spend_key() == spend_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::wallet::wallet_types::TransactionStatus;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    const ADDRESS: &str = "f25eNHz2YnBVKHaqNuacGyDFB321RwwCnTr4vb2SjQCgDZVXyNNthc7zftQKRDu6evLjvSUD8W5akpPMdhS4HQ9kF3g";
+
+    fn transaction(amount: u64, memo: &str, dest_address: &str) -> TransactionInfo {
+        TransactionInfo {
+            tx_id: "1".to_string(),
+            source_address: String::new(),
+            dest_address: dest_address.to_string(),
+            status: TransactionStatus::MinedConfirmed,
+            amount: MicroMinotari(amount),
+            is_cancelled: false,
+            direction: 2,
+            excess_sig: vec![],
+            fee: 0,
+            timestamp: 0,
+            payment_id: memo.to_string(),
+            mined_in_block_height: 10,
+            payment_reference: None,
+        }
+    }
+
+    fn search(query: TransactionSearchQuery) -> TransactionSearch {
+        TransactionSearch::try_from(query).unwrap()
+    }
+
+    #[test]
+    fn memo_search_needs_every_word() {
+        let transaction = transaction(100, "Invoice 42 for March hosting", "");
+        let matching = search(TransactionSearchQuery {
+            memo: Some("march INVOICE".to_string()),
+            ..Default::default()
+        });
+        let missing_word = search(TransactionSearchQuery {
+            memo: Some("invoice april".to_string()),
+            ..Default::default()
+        });
+
+        assert!(matching.matches(&transaction));
+        assert!(!missing_word.matches(&transaction));
+    }
+
+    #[test]
+    fn payment_id_is_exact_and_amount_range_applies() {
+        let transaction = transaction(500, "order-7", "");
+        let query = |payment_id: &str, min_amount: u64| {
+            search(TransactionSearchQuery {
+                payment_id: Some(payment_id.to_string()),
+                filter: TransactionHistoryFilter {
+                    min_amount: Some(MicroMinotari(min_amount)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+        };
+
+        assert!(query("order-7", 100).matches(&transaction));
+        assert!(!query("order", 100).matches(&transaction));
+        assert!(!query("order-7", 1_000).matches(&transaction));
+    }
+
+    #[test]
+    fn address_matches_across_formats() {
+        let address = TariAddress::from_base58(ADDRESS).unwrap();
+        let transaction = transaction(100, "", ADDRESS);
+
+        for format in [
+            address.to_emoji_string(),
+            address.to_hex(),
+            ADDRESS.to_string(),
+        ] {
+            let query = search(TransactionSearchQuery {
+                address: Some(format),
+                ..Default::default()
+            });
+            assert!(query.matches(&transaction));
+        }
+        assert!(TransactionSearch::try_from(TransactionSearchQuery {
+            address: Some("not an address".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
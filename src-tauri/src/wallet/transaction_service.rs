@@ -20,6 +20,9 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::wallet::offline_signing::{
+    read_tx_id, signed_tx_file_name, unsigned_tx_file_name, validate_signed_transaction,
+};
 use crate::wallet::spend_wallet::SpendWallet;
 use crate::wallet::wallet_adapter::WalletAdapter;
 use crate::wallet::wallet_status_monitor::WalletStatusMonitorError;
@@ -30,8 +33,9 @@ use minotari_node_grpc_client::grpc::{
     PrepareOneSidedTransactionForSigningRequest, UserPaymentId,
 };
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tari_common::configuration::Network;
+use tari_utilities::SafePassword;
 use tauri::Manager;
 
 const LOG_TARGET: &str = "tari::universe::transaction_service";
@@ -56,6 +60,7 @@ impl<'a> TransactionService<'a> {
     /// * `amount` - Amount to send(MicroMinotari as u64)
     /// * `address` - Recipient's stealth address
     /// * `payment_id` - Optional utf8_string Payment ID for the transaction
+    /// * `fee_per_gram` - Fee per gram(MicroMinotari as u64), fixed for signing and broadcast
     ///
     /// # Returns
     /// * `Result<(PathBuf, String), anyhow::Error>` - Path to the unsigned transaction file and transaction ID
@@ -64,6 +69,7 @@ impl<'a> TransactionService<'a> {
         amount: u64,
         address: String,
         payment_id: Option<String>,
+        fee_per_gram: u64,
     ) -> Result<(PathBuf, String), anyhow::Error> {
         let payment_recipient = PaymentRecipient {
             address,
@@ -74,7 +80,7 @@ impl<'a> TransactionService<'a> {
                 u256: vec![],
                 user_bytes: vec![],
             }),
-            fee_per_gram: 1, // TODO: Implement fee calculation logic
+            fee_per_gram,
             payment_type: PaymentType::OneSidedToStealthAddress.into(),
         };
 
@@ -108,25 +114,18 @@ impl<'a> TransactionService<'a> {
         }
 
         // Extract transaction ID from the JSON response
-        let parsed: serde_json::Value = serde_json::from_str(&unsigned_tx_json)
-            .expect("Failed to parse unsigned one-sided transaction JSON");
-        let tx_id = if let Some(tx_id) = parsed.get("tx_id") {
-            tx_id.to_string().trim_matches('"').to_string()
-        } else {
-            return Err(anyhow::anyhow!(
-                "One-sided transaction ID not found in JSON file"
-            ));
-        };
+        let tx_id = read_tx_id(&unsigned_tx_json)?;
 
         // Save unsigned transaction to file
-        let unsigned_tx_file = wallet_txs_dir.join(format!("{tx_id}-unsigned.json"));
+        let unsigned_tx_file = wallet_txs_dir.join(unsigned_tx_file_name(&tx_id));
         fs::write(&unsigned_tx_file, &unsigned_tx_json)?;
 
         Ok((unsigned_tx_file, tx_id))
     }
 
     /// Cancel a transaction
-    /// Used as cleanup after failing to sign one sided transaction by spend wallet
+    /// Used as cleanup after failing to sign one sided transaction by spend wallet and to release
+    /// the inputs of a stuck outgoing transaction
     ///
     /// # Arguments
     /// * `tx_id` - The ID of the transaction to cancel
@@ -135,8 +134,8 @@ impl<'a> TransactionService<'a> {
     /// * `Result<(), anyhow::Error>` - A result indicating success or failure
     pub async fn cancel_transaction(&self, tx_id: String) -> Result<(), anyhow::Error> {
         let wallet_txs_dir = get_transactions_directory(self.app_handle)?;
-        let unsigned_tx_file = wallet_txs_dir.join(format!("{tx_id}-unsigned.json"));
-        let signed_tx_file = wallet_txs_dir.join(format!("{tx_id}.json"));
+        let unsigned_tx_file = wallet_txs_dir.join(unsigned_tx_file_name(&tx_id));
+        let signed_tx_file = wallet_txs_dir.join(signed_tx_file_name(&tx_id));
 
         let tx_id_u64 = tx_id
             .parse::<u64>()
@@ -152,14 +151,18 @@ impl<'a> TransactionService<'a> {
         let cancel_tx_res = res.into_inner();
         if !cancel_tx_res.is_success {
             return Err(anyhow::anyhow!(
-                "One-sided transaction preparation failed: {}",
+                "Transaction cancellation failed: {}",
                 cancel_tx_res.failure_message
             ));
         };
 
-        // Remove unsigned and signed transaction files
-        fs::remove_file(&unsigned_tx_file)?;
-        fs::remove_file(&signed_tx_file)?;
+        // Remove unsigned and signed transaction files, offline transactions may not have a signed file yet
+        if unsigned_tx_file.exists() {
+            fs::remove_file(&unsigned_tx_file)?;
+        }
+        if signed_tx_file.exists() {
+            fs::remove_file(&signed_tx_file)?;
+        }
 
         Ok(())
     }
@@ -169,6 +172,7 @@ impl<'a> TransactionService<'a> {
     /// # Arguments
     /// * `unsigned_tx_file` - Path to the unsigned transaction file
     /// * `tx_id` - Transaction ID
+    /// * `pin_password` - Validated PIN, required when the wallet is PIN locked
     ///
     /// # Returns
     /// * `Result<PathBuf, anyhow::Error>` - Path to the signed transaction file
@@ -176,10 +180,11 @@ impl<'a> TransactionService<'a> {
         &self,
         unsigned_tx_file: PathBuf,
         tx_id: String,
+        pin_password: Option<SafePassword>,
     ) -> Result<PathBuf, anyhow::Error> {
         // Define the output file path for the signed transaction
         let wallet_txs_dir = get_transactions_directory(self.app_handle)?;
-        let signed_tx_destination_file = wallet_txs_dir.join(format!("{tx_id}.json"));
+        let signed_tx_destination_file = wallet_txs_dir.join(signed_tx_file_name(&tx_id));
 
         // Sign the transaction using SpendWallet
         let spend_wallet = SpendWallet::new();
@@ -187,6 +192,7 @@ impl<'a> TransactionService<'a> {
             .sign_one_sided_transaction(
                 unsigned_tx_file,
                 signed_tx_destination_file.clone(),
+                pin_password,
                 self.app_handle,
             )
             .await?;
@@ -194,6 +200,70 @@ impl<'a> TransactionService<'a> {
         Ok(signed_tx_destination_file)
     }
 
+    /// Copies a prepared unsigned transaction to a file that is carried to the offline signer
+    ///
+    /// # Arguments
+    /// * `unsigned_tx_file` - Path to the unsigned transaction file
+    /// * `destination` - User chosen path for the exported file
+    ///
+    /// # Returns
+    /// * `Result<(), anyhow::Error>` - Success or failure
+    pub fn export_unsigned_tx(
+        &self,
+        unsigned_tx_file: &Path,
+        destination: &Path,
+    ) -> Result<(), anyhow::Error> {
+        fs::copy(unsigned_tx_file, destination)?;
+        Ok(())
+    }
+
+    /// Validates a file signed on the offline signer against the transaction prepared on this
+    /// machine and stores it next to the unsigned file
+    ///
+    /// # Arguments
+    /// * `source` - Path to the signed transaction file
+    ///
+    /// # Returns
+    /// * `Result<(PathBuf, String), anyhow::Error>` - Path to the stored signed file and transaction ID
+    pub fn import_signed_tx(&self, source: &Path) -> Result<(PathBuf, String), anyhow::Error> {
+        let signed_tx_json = fs::read_to_string(source)?;
+        let tx_id = read_tx_id(&signed_tx_json)?;
+
+        let wallet_txs_dir = get_transactions_directory(self.app_handle)?;
+        let unsigned_tx_file = wallet_txs_dir.join(unsigned_tx_file_name(&tx_id));
+        if !unsigned_tx_file.exists() {
+            return Err(anyhow::anyhow!(
+                "Transaction {tx_id} was not prepared on this machine"
+            ));
+        }
+        let unsigned_tx_json = fs::read_to_string(&unsigned_tx_file)?;
+        validate_signed_transaction(&signed_tx_json, &unsigned_tx_json)?;
+
+        let signed_tx_file = wallet_txs_dir.join(signed_tx_file_name(&tx_id));
+        fs::write(&signed_tx_file, &signed_tx_json)?;
+
+        Ok((signed_tx_file, tx_id))
+    }
+
+    /// Reads the stored signed file of a transaction, used to resubmit it to the mempool
+    ///
+    /// # Arguments
+    /// * `tx_id` - The ID of the transaction to rebroadcast
+    ///
+    /// # Returns
+    /// * `Result<String, anyhow::Error>` - Contents of the signed transaction file
+    pub fn read_signed_tx(&self, tx_id: &str) -> Result<String, anyhow::Error> {
+        let wallet_txs_dir = get_transactions_directory(self.app_handle)?;
+        let signed_tx_file = wallet_txs_dir.join(signed_tx_file_name(tx_id));
+        if !signed_tx_file.exists() {
+            return Err(anyhow::anyhow!(
+                "Signed transaction {tx_id} is not stored on this machine and can't be rebroadcast"
+            ));
+        }
+
+        Ok(fs::read_to_string(&signed_tx_file)?)
+    }
+
     /// Broadcasts a signed one-sided transaction to the network
     ///
     /// # Arguments
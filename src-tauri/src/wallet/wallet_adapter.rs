@@ -26,6 +26,9 @@ use crate::process_adapter_utils::setup_working_directory;
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::file_utils::convert_to_string;
 use crate::utils::logging_utils::setup_logging;
+use crate::wallet::transaction_history::{
+    TransactionHistoryCache, PAYMENT_REFERENCE_CONFIRMATIONS,
+};
 use crate::wallet::transaction_service::TransactionService;
 use crate::wallet::wallet_status_monitor::{WalletStatusMonitor, WalletStatusMonitorError};
 use crate::wallet::wallet_types::{
@@ -34,7 +37,10 @@ use crate::wallet::wallet_types::{
 use anyhow::Error;
 use log::{info, warn};
 use minotari_node_grpc_client::grpc::wallet_client::WalletClient;
-use minotari_node_grpc_client::grpc::{GetAllCompletedTransactionsRequest, GetBalanceRequest};
+use minotari_node_grpc_client::grpc::{
+    BlockHeight, Empty, GetBalanceRequest, GetCompletedTransactionsRequest,
+    GetTransactionInfoRequest, TransactionInfo as GrpcTransactionInfo,
+};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -44,6 +50,7 @@ use tari_common_types::tari_address::{TariAddress, TariAddressError};
 use tari_shutdown::Shutdown;
 use tari_transaction_components::tari_amount::MicroMinotari;
 use tari_transaction_components::transaction_components::memo_field::MemoField;
+use tari_utilities::SafePassword;
 use tokio::sync::watch;
 
 #[cfg(target_os = "windows")]
@@ -83,6 +90,8 @@ pub struct WalletAdapter {
     pub(crate) state_broadcast: watch::Sender<Option<WalletState>>,
     pub(crate) wallet_birthday: Option<u16>,
     pub(crate) http_client_url: Option<String>,
+    // Relative to the data dir, every internal wallet has its own folder
+    pub(crate) data_dir_name: String,
 }
 
 impl WalletAdapter {
@@ -99,6 +108,7 @@ impl WalletAdapter {
             state_broadcast,
             wallet_birthday: None,
             http_client_url: None,
+            data_dir_name: "wallet".to_string(),
         }
     }
 
@@ -122,84 +132,161 @@ impl WalletAdapter {
         Ok(WalletBalance::from_response(balance))
     }
 
-    pub async fn get_transactions(
+    pub async fn get_unspent_output_count(&self) -> Result<u64, anyhow::Error> {
+        let mut client = WalletClient::connect(self.wallet_grpc_address())
+            .await
+            .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
+        let res = client.get_unspent_amounts(Empty {}).await?;
+
+        Ok(res.into_inner().amount.len() as u64)
+    }
+
+    /// Streams the full completed transaction history from the wallet
+    pub async fn stream_transactions(
+        &self,
+        current_block_height: u64,
+    ) -> Result<Vec<TransactionInfo>, WalletStatusMonitorError> {
+        self.stream_completed_transactions(
+            GetCompletedTransactionsRequest::default(),
+            current_block_height,
+        )
+        .await
+    }
+
+    /// Streams the transactions mined in the block at `block_height`
+    pub async fn stream_transactions_mined_at(
+        &self,
+        block_height: u64,
+        current_block_height: u64,
+    ) -> Result<Vec<TransactionInfo>, WalletStatusMonitorError> {
+        self.stream_completed_transactions(
+            GetCompletedTransactionsRequest {
+                block_height: Some(BlockHeight { block_height }),
+                ..Default::default()
+            },
+            current_block_height,
+        )
+        .await
+    }
+
+    /// Looks up transactions by ID, IDs the wallet does not know are left out
+    pub async fn get_transactions_by_id(
         &self,
-        offset: Option<u32>,
-        limit: Option<u32>,
-        status: Option<u32>,
+        tx_ids: &[String],
         current_block_height: u64,
     ) -> Result<Vec<TransactionInfo>, WalletStatusMonitorError> {
+        let transaction_ids = tx_ids
+            .iter()
+            .map(|tx_id| tx_id.parse::<u64>())
+            .collect::<Result<Vec<u64>, _>>()
+            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?;
+        if transaction_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
         let mut client = WalletClient::connect(self.wallet_grpc_address())
             .await
             .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
-        // TODO: This needs to be upgraded to the streaming API https://github.com/tari-project/tari/pull/7366/
-        #[allow(deprecated)]
         let res = client
-            .get_all_completed_transactions(GetAllCompletedTransactionsRequest {
-                offset: u64::from(offset.unwrap_or(0)),
-                limit: u64::from(limit.unwrap_or(0)),
-                status_bitflag: u64::from(status.unwrap_or(0)),
-            })
+            .get_transaction_info(GetTransactionInfoRequest { transaction_ids })
             .await
             .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?;
 
-        let transactions = res
-            .into_inner()
+        res.into_inner()
             .transactions
             .into_iter()
-            .map(|tx| {
-                let confirmations = if current_block_height > 0
-                    && tx.mined_in_block_height <= current_block_height
-                {
-                    current_block_height - tx.mined_in_block_height
-                } else {
-                    0
-                };
-                let payment_reference = if confirmations >= 5 {
-                    match tx.direction {
-                        1 => tx.payment_references_received.last().map(hex::encode),
-                        2 => tx.payment_references_sent.last().map(hex::encode),
-                        _ => None,
-                    }
-                } else {
-                    None
-                };
-
-                Ok(TransactionInfo {
-                    tx_id: tx.tx_id.to_string(),
-                    source_address: TariAddress::from_bytes(&tx.source_address)?.to_base58(),
-                    dest_address: TariAddress::from_bytes(&tx.dest_address)?.to_base58(),
-                    status: TransactionStatus::from(tx.status),
-                    amount: MicroMinotari(tx.amount),
-                    is_cancelled: tx.is_cancelled,
-                    direction: tx.direction,
-                    excess_sig: tx.excess_sig,
-                    fee: tx.fee,
-                    timestamp: tx.timestamp,
-                    payment_id: MemoField::stringify_bytes(&tx.user_payment_id),
-                    mined_in_block_height: tx.mined_in_block_height,
-                    payment_reference,
-                })
-            })
-            .collect::<Result<Vec<_>, TariAddressError>>()?;
+            .filter(|tx| tx.status != TransactionStatus::NotFound as i32)
+            .map(|tx| Ok(Self::to_transaction_info(tx, current_block_height)?))
+            .collect()
+    }
+
+    async fn stream_completed_transactions(
+        &self,
+        request: GetCompletedTransactionsRequest,
+        current_block_height: u64,
+    ) -> Result<Vec<TransactionInfo>, WalletStatusMonitorError> {
+        let mut client = WalletClient::connect(self.wallet_grpc_address())
+            .await
+            .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
+        let mut stream = client
+            .get_completed_transactions(request)
+            .await
+            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?
+            .into_inner();
+
+        let mut transactions = Vec::new();
+        while let Some(response) = stream
+            .message()
+            .await
+            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?
+        {
+            if let Some(tx) = response.transaction {
+                transactions.push(Self::to_transaction_info(tx, current_block_height)?);
+            }
+        }
 
         Ok(transactions)
     }
 
+    fn to_transaction_info(
+        tx: GrpcTransactionInfo,
+        current_block_height: u64,
+    ) -> Result<TransactionInfo, TariAddressError> {
+        let confirmations =
+            if current_block_height > 0 && tx.mined_in_block_height <= current_block_height {
+                current_block_height - tx.mined_in_block_height
+            } else {
+                0
+            };
+        let payment_reference = if confirmations >= PAYMENT_REFERENCE_CONFIRMATIONS {
+            match tx.direction {
+                1 => tx.payment_references_received.last().map(hex::encode),
+                2 => tx.payment_references_sent.last().map(hex::encode),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(TransactionInfo {
+            tx_id: tx.tx_id.to_string(),
+            source_address: TariAddress::from_bytes(&tx.source_address)?.to_base58(),
+            dest_address: TariAddress::from_bytes(&tx.dest_address)?.to_base58(),
+            status: TransactionStatus::from(tx.status),
+            amount: MicroMinotari(tx.amount),
+            is_cancelled: tx.is_cancelled,
+            direction: tx.direction,
+            excess_sig: tx.excess_sig,
+            fee: tx.fee,
+            timestamp: tx.timestamp,
+            payment_id: MemoField::stringify_bytes(&tx.user_payment_id),
+            mined_in_block_height: tx.mined_in_block_height,
+            payment_reference,
+        })
+    }
+
+    /// `pin_password` is asked for by the caller, so a batch of sends only prompts for it once
     pub async fn send_one_sided_to_stealth_address(
         &self,
         amount: u64,
         address: String,
         payment_id: Option<String>,
+        fee_per_gram: u64,
+        pin_password: Option<SafePassword>,
         app_handle: &tauri::AppHandle,
     ) -> Result<(), anyhow::Error> {
         let tx_service = TransactionService::new(self, app_handle);
 
         let (unsigned_tx_file, tx_id) = tx_service
-            .prepare_one_sided_transaction_for_signing(amount, address, payment_id)
+            .prepare_one_sided_transaction_for_signing(amount, address, payment_id, fee_per_gram)
             .await?;
+        info!(target: LOG_TARGET, "Prepared one-sided transaction {tx_id} with fee per gram: {fee_per_gram}");
+        TransactionHistoryCache::current()
+            .write()
+            .await
+            .track(tx_id.clone());
         let sign_result = tx_service
-            .sign_one_sided_tx(unsigned_tx_file, tx_id.clone())
+            .sign_one_sided_tx(unsigned_tx_file, tx_id.clone(), pin_password)
             .await;
         match sign_result {
             Ok(signed_tx_file) => tx_service.broadcast_one_sided_tx(signed_tx_file).await,
@@ -213,6 +300,71 @@ impl WalletAdapter {
         }
     }
 
+    /// Prepares a one-sided transaction and exports it to `destination` for signing on an offline
+    /// machine, returning the transaction ID
+    pub async fn prepare_offline_one_sided_transaction(
+        &self,
+        amount: u64,
+        address: String,
+        payment_id: Option<String>,
+        fee_per_gram: u64,
+        destination: &Path,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<String, anyhow::Error> {
+        let tx_service = TransactionService::new(self, app_handle);
+
+        let (unsigned_tx_file, tx_id) = tx_service
+            .prepare_one_sided_transaction_for_signing(amount, address, payment_id, fee_per_gram)
+            .await?;
+        TransactionHistoryCache::current()
+            .write()
+            .await
+            .track(tx_id.clone());
+        if let Err(e) = tx_service.export_unsigned_tx(&unsigned_tx_file, destination) {
+            let cancel_res = tx_service.cancel_transaction(tx_id).await;
+            if let Err(cancel_err) = cancel_res {
+                log::error!(target: LOG_TARGET, "Failed to cancel transaction after failed to export unsigned tx: {cancel_err}: {e}");
+            }
+            return Err(e);
+        }
+        info!(target: LOG_TARGET, "Exported unsigned one-sided transaction {tx_id} to {}", destination.display());
+
+        Ok(tx_id)
+    }
+
+    /// Validates a transaction signed on an offline machine and broadcasts it, returning the
+    /// transaction ID
+    pub async fn broadcast_offline_signed_transaction(
+        &self,
+        signed_tx_file: &Path,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<String, anyhow::Error> {
+        let tx_service = TransactionService::new(self, app_handle);
+
+        let (signed_tx_file, tx_id) = tx_service.import_signed_tx(signed_tx_file)?;
+        tx_service.broadcast_one_sided_tx(signed_tx_file).await?;
+
+        Ok(tx_id)
+    }
+
+    pub async fn cancel_transaction(
+        &self,
+        tx_id: String,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<(), anyhow::Error> {
+        TransactionService::new(self, app_handle)
+            .cancel_transaction(tx_id)
+            .await
+    }
+
+    pub fn read_signed_transaction(
+        &self,
+        tx_id: &str,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<String, anyhow::Error> {
+        TransactionService::new(self, app_handle).read_signed_tx(tx_id)
+    }
+
     pub async fn wait_for_scan_to_height(
         &self,
         block_height: u64,
@@ -295,7 +447,7 @@ impl ProcessAdapter for WalletAdapter {
         info!(target: LOG_TARGET, "Starting read only wallet");
 
         // Setup working directory using shared utility
-        let working_dir = setup_working_directory(&data_dir, "wallet")?;
+        let working_dir = setup_working_directory(&data_dir, &self.data_dir_name)?;
         let network_dir = working_dir.join(Network::get_current().to_string().to_lowercase());
         let config_dir = network_dir.join("config");
 
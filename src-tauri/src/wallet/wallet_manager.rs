@@ -25,20 +25,42 @@ use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
 use crate::internal_wallet::InternalWallet;
 use crate::node::node_manager::{NodeManager, NodeManagerError};
+use crate::pin::PinManager;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
 use crate::tasks_tracker::TasksTrackers;
+use crate::utils::sync_progress::SyncRateEstimator;
+use crate::wallet::auto_sweep::sweep_amount;
+use crate::wallet::batch_payment::{
+    BatchPaymentRecipient, BatchPaymentStatus, BatchPaymentSummary, MAX_BATCH_RECIPIENTS,
+};
+use crate::wallet::coinbase_maturity::{
+    coinbase_maturity_blocks, coinbase_maturity_schedule, CoinbaseMaturityTracker,
+    CoinbaseOutputMaturity,
+};
+use crate::wallet::fee_estimation::{
+    estimated_fee, FeeEstimate, FEE_STATS_BLOCK_COUNT, MIN_FEE_PER_GRAM,
+};
+use crate::wallet::offline_signing::signed_transaction_for_node;
+use crate::wallet::payment_requests::check_payment_requests;
+use crate::wallet::stuck_transactions::{ensure_cancellable, ensure_rebroadcastable};
+use crate::wallet::transaction_history::{
+    TransactionHistoryCache, TransactionHistoryFilter, TransactionHistoryUpdatePayload,
+};
+use crate::wallet::transaction_search::TransactionSearch;
 use crate::wallet::wallet_adapter::WalletAdapter;
+use crate::wallet::wallet_rescan::birthday_from_timestamp;
 use crate::wallet::wallet_status_monitor::WalletStatusMonitorError;
 use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus, WalletBalance, WalletState};
 use crate::BaseNodeStatus;
 use futures_util::future::FusedFuture;
-use log::info;
+use log::{info, warn};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tari_common::configuration::Network;
 use tari_shutdown::ShutdownSignal;
 use tari_transaction_components::tari_amount::{MicroMinotari, Minotari};
@@ -47,6 +69,9 @@ use tokio::sync::watch;
 use tokio::sync::RwLock;
 
 static LOG_TARGET: &str = "tari::universe::wallet_manager";
+const COINBASE_STATUSES_BITFLAG: u32 = (1 << TransactionStatus::CoinbaseConfirmed as u32)
+    | (1 << TransactionStatus::CoinbaseUnconfirmed as u32);
+const TRANSACTION_HISTORY_SYNC_INTERVAL: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Clone)]
 pub struct WalletStartupConfig {
@@ -72,6 +97,8 @@ pub struct WalletManager {
     node_manager: NodeManager,
     initial_scan_completed: Arc<AtomicBool>,
     base_node_watch_rx: watch::Receiver<BaseNodeStatus>,
+    // Birthday the next start scans from instead of the seed birthday
+    pending_birthday: Arc<RwLock<Option<u16>>>,
 }
 
 impl Clone for WalletManager {
@@ -81,6 +108,7 @@ impl Clone for WalletManager {
             node_manager: self.node_manager.clone(),
             initial_scan_completed: self.initial_scan_completed.clone(),
             base_node_watch_rx: self.base_node_watch_rx.clone(),
+            pending_birthday: self.pending_birthday.clone(),
         }
     }
 }
@@ -100,6 +128,7 @@ impl WalletManager {
             node_manager,
             initial_scan_completed: Arc::new(AtomicBool::new(false)),
             base_node_watch_rx,
+            pending_birthday: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -133,7 +162,12 @@ impl WalletManager {
             .connect_with_local_node(config.connect_with_local_node);
 
         let tari_wallet_details = InternalWallet::tari_wallet_details().await;
-        process_watcher.adapter.wallet_birthday = tari_wallet_details.map(|d| d.wallet_birthday);
+        let pending_birthday = self.pending_birthday.write().await.take();
+        process_watcher.adapter.wallet_birthday =
+            pending_birthday.or(tari_wallet_details.map(|d| d.wallet_birthday));
+        process_watcher.adapter.data_dir_name = ConfigWallet::content()
+            .await
+            .selected_wallet_data_dir_name();
 
         process_watcher
             .start(
@@ -174,21 +208,41 @@ impl WalletManager {
     }
 
     pub async fn clean_data_folder(&self, base_path: &Path) -> Result<(), anyhow::Error> {
-        self.initial_scan_completed
-            .store(false, std::sync::atomic::Ordering::Relaxed);
-
+        let data_dir_name = ConfigWallet::content()
+            .await
+            .selected_wallet_data_dir_name();
         let path_to_network_wallet = base_path
-            .join("wallet")
+            .join(data_dir_name)
             .join(Network::get_current().to_string().to_lowercase());
 
         if path_to_network_wallet.try_exists()? && path_to_network_wallet.is_dir() {
             fs::remove_dir_all(path_to_network_wallet).await?;
         }
+        self.reset_wallet_caches().await;
 
         log::info!(target: LOG_TARGET, "Cleaning wallet data folder");
         Ok(())
     }
 
+    /// Drops everything cached for the selected wallet, used when its data is removed or another wallet is selected
+    pub async fn reset_wallet_caches(&self) {
+        self.initial_scan_completed
+            .store(false, std::sync::atomic::Ordering::Relaxed);
+        TransactionHistoryCache::current().write().await.clear();
+        CoinbaseMaturityTracker::current().write().await.reset();
+    }
+
+    /// Used by the next wallet start, together with a cleared data folder it rescans from `birthday`
+    pub async fn set_pending_birthday(&self, birthday: u16) {
+        *self.pending_birthday.write().await = Some(birthday);
+    }
+
+    /// Birthday covering the block at `height`, based on the block timestamp
+    pub async fn birthday_for_height(&self, height: u64) -> Result<u16, WalletManagerError> {
+        let block = self.node_manager.get_block_details(height).await?;
+        Ok(birthday_from_timestamp(block.block.timestamp))
+    }
+
     pub async fn get_balance(&self) -> Result<WalletBalance, anyhow::Error> {
         let process_watcher = self.watcher.read().await;
         process_watcher.adapter.get_balance().await
@@ -200,17 +254,196 @@ impl WalletManager {
         limit: Option<u32>,
         status_bitflag: Option<u32>,
     ) -> Result<Vec<TransactionInfo>, WalletManagerError> {
-        let node_status = *self.base_node_watch_rx.borrow();
-        let current_block_height = node_status.block_height;
-        let process_watcher = self.watcher.read().await;
-        process_watcher
-            .adapter
-            .get_transactions(offset, limit, status_bitflag, current_block_height)
+        self.get_transaction_history(
+            &TransactionHistoryFilter::with_status_bitflag(status_bitflag),
+            offset,
+            limit,
+        )
+        .await
+    }
+
+    /// Served from the transaction history cache, which is synced first when a new block has
+    /// arrived since the last sync
+    pub async fn get_transaction_history(
+        &self,
+        filter: &TransactionHistoryFilter,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TransactionInfo>, WalletManagerError> {
+        self.sync_transaction_history_if_outdated().await?;
+
+        Ok(TransactionHistoryCache::current()
+            .read()
             .await
-            .map_err(|e| match e {
-                WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
-                _ => WalletManagerError::UnknownError(e.into()),
-            })
+            .query(filter, offset, limit))
+    }
+
+    /// Searches the transaction history cache, synced the same way as `get_transaction_history`
+    pub async fn search_transactions(
+        &self,
+        search: &TransactionSearch,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TransactionInfo>, WalletManagerError> {
+        self.sync_transaction_history_if_outdated().await?;
+
+        Ok(TransactionHistoryCache::current()
+            .read()
+            .await
+            .search(search, offset, limit))
+    }
+
+    /// Whether this wallet received the coinbase of the block at `block_height`, looked up in the
+    /// whole transaction history cache rather than only the latest coinbases
+    pub async fn is_block_won(&self, block_height: u64) -> Result<bool, WalletManagerError> {
+        self.sync_transaction_history_if_outdated().await?;
+
+        Ok(TransactionHistoryCache::current()
+            .read()
+            .await
+            .find_coinbase_mined_at(block_height)
+            .is_some())
+    }
+
+    /// Heights of all the blocks this wallet received a coinbase for, from the transaction
+    /// history cache like `is_block_won`
+    pub async fn get_won_block_heights(&self) -> Result<HashSet<u64>, WalletManagerError> {
+        self.sync_transaction_history_if_outdated().await?;
+
+        Ok(TransactionHistoryCache::current()
+            .read()
+            .await
+            .won_block_heights())
+    }
+
+    async fn sync_transaction_history_if_outdated(&self) -> Result<(), WalletManagerError> {
+        let current_block_height = self.base_node_watch_rx.borrow().block_height;
+        let synced_block_height = TransactionHistoryCache::current()
+            .read()
+            .await
+            .synced_block_height();
+        if synced_block_height != Some(current_block_height) {
+            self.sync_transaction_history().await?;
+        }
+        Ok(())
+    }
+
+    /// Pulls the history changes from the wallet into the cache and emits them, if any. Only the
+    /// first sync lists the full history, later ones pull the newly scanned blocks and refresh
+    /// the transactions that are not final yet.
+    pub async fn sync_transaction_history(
+        &self,
+    ) -> Result<TransactionHistoryUpdatePayload, WalletManagerError> {
+        let current_block_height = self.base_node_watch_rx.borrow().block_height;
+        let update = {
+            let process_watcher = self.watcher.read().await;
+            let adapter = &process_watcher.adapter;
+            let scanned_height = adapter
+                .state_broadcast
+                .borrow()
+                .as_ref()
+                .map(|state| state.scanned_height);
+            let (sync_range, refresh_tx_ids) = {
+                let cache = TransactionHistoryCache::current().read().await;
+                (
+                    scanned_height.and_then(|height| cache.incremental_sync_range(height)),
+                    cache.refresh_tx_ids(current_block_height),
+                )
+            };
+
+            match (sync_range, scanned_height) {
+                (Some(sync_range), Some(scanned_height)) => {
+                    let mut transactions = adapter
+                        .get_transactions_by_id(&refresh_tx_ids, current_block_height)
+                        .await
+                        .map_err(to_wallet_manager_error)?;
+                    for block_height in sync_range {
+                        transactions.extend(
+                            adapter
+                                .stream_transactions_mined_at(block_height, current_block_height)
+                                .await
+                                .map_err(to_wallet_manager_error)?,
+                        );
+                    }
+                    TransactionHistoryCache::current()
+                        .write()
+                        .await
+                        .apply_incremental(
+                            &refresh_tx_ids,
+                            transactions,
+                            current_block_height,
+                            scanned_height,
+                        )
+                }
+                _ => {
+                    let transactions = adapter
+                        .stream_transactions(current_block_height)
+                        .await
+                        .map_err(to_wallet_manager_error)?;
+                    TransactionHistoryCache::current().write().await.apply(
+                        transactions,
+                        current_block_height,
+                        scanned_height,
+                    )
+                }
+            }
+        };
+
+        let transactions = TransactionHistoryCache::current().read().await.query(
+            &TransactionHistoryFilter::default(),
+            None,
+            None,
+        );
+        check_payment_requests(&transactions).await;
+        if !update.is_empty() {
+            EventsEmitter::emit_transaction_history_update(update.clone()).await;
+        }
+
+        let schedule = self
+            .coinbase_maturity_schedule_at(current_block_height)
+            .await;
+        let matured = CoinbaseMaturityTracker::current()
+            .write()
+            .await
+            .advance(&schedule, current_block_height);
+        if let Some(matured) = matured {
+            info!(target: LOG_TARGET, "{} coinbase rewards ({}) became spendable at height {}", matured.count, matured.amount, matured.block_height);
+            EventsEmitter::emit_coinbase_rewards_matured(matured).await;
+        }
+        Ok(update)
+    }
+
+    /// Mined coinbase outputs with the height and estimated time they become spendable at
+    pub async fn get_coinbase_maturity_schedule(
+        &self,
+    ) -> Result<Vec<CoinbaseOutputMaturity>, WalletManagerError> {
+        self.sync_transaction_history_if_outdated().await?;
+
+        let current_block_height = self.base_node_watch_rx.borrow().block_height;
+        Ok(self
+            .coinbase_maturity_schedule_at(current_block_height)
+            .await)
+    }
+
+    async fn coinbase_maturity_schedule_at(
+        &self,
+        current_block_height: u64,
+    ) -> Vec<CoinbaseOutputMaturity> {
+        let transactions = TransactionHistoryCache::current().read().await.query(
+            &TransactionHistoryFilter::default(),
+            None,
+            None,
+        );
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        coinbase_maturity_schedule(
+            &transactions,
+            current_block_height,
+            now,
+            coinbase_maturity_blocks(Network::get_current_or_user_setting_or_default()),
+        )
     }
 
     pub async fn wait_for_scan_to_height(
@@ -239,6 +472,7 @@ impl WalletManager {
         amount_str: String,
         destination: String,
         payment_id: Option<String>,
+        fee_per_gram: Option<u64>,
         app_handle: &tauri::AppHandle,
     ) -> Result<(), WalletManagerError> {
         let process_watcher = self.watcher.read().await;
@@ -260,21 +494,340 @@ impl WalletManager {
             _ => payment_id,
         };
 
+        let fee_per_gram = self.resolve_fee_per_gram(fee_per_gram).await?;
+        let pin_password = PinManager::get_validated_pin_if_defined(app_handle).await?;
+
         let res = process_watcher
             .adapter
-            .send_one_sided_to_stealth_address(amount, destination, payment_id, app_handle)
+            .send_one_sided_to_stealth_address(
+                amount,
+                destination,
+                payment_id,
+                fee_per_gram,
+                pin_password,
+                app_handle,
+            )
             .await;
 
         res.map_err(WalletManagerError::UnknownError)
     }
 
+    /// Prepares a one-sided transaction for an offline signer and exports it to `destination`
+    pub async fn prepare_offline_transaction(
+        &self,
+        amount_str: String,
+        destination: String,
+        payment_id: Option<String>,
+        fee_per_gram: Option<u64>,
+        export_path: &Path,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<String, WalletManagerError> {
+        let process_watcher = self.watcher.read().await;
+        if !process_watcher.is_running() {
+            return Err(WalletManagerError::WalletNotStarted);
+        }
+
+        self.node_manager.wait_ready().await?;
+
+        let minotari_amount = Minotari::from_str(&amount_str)
+            .map_err(|e| WalletManagerError::UnknownError(e.into()))?;
+        let amount = MicroMinotari::from(minotari_amount).as_u64();
+        let payment_id = payment_id.filter(|id| !id.is_empty());
+        let fee_per_gram = self.resolve_fee_per_gram(fee_per_gram).await?;
+
+        process_watcher
+            .adapter
+            .prepare_offline_one_sided_transaction(
+                amount,
+                destination,
+                payment_id,
+                fee_per_gram,
+                export_path,
+                app_handle,
+            )
+            .await
+            .map_err(WalletManagerError::UnknownError)
+    }
+
+    pub async fn broadcast_offline_signed_transaction(
+        &self,
+        signed_tx_file: &Path,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<String, WalletManagerError> {
+        let process_watcher = self.watcher.read().await;
+        if !process_watcher.is_running() {
+            return Err(WalletManagerError::WalletNotStarted);
+        }
+
+        self.node_manager.wait_ready().await?;
+
+        process_watcher
+            .adapter
+            .broadcast_offline_signed_transaction(signed_tx_file, app_handle)
+            .await
+            .map_err(WalletManagerError::UnknownError)
+    }
+
+    pub async fn cancel_offline_transaction(
+        &self,
+        tx_id: String,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<(), WalletManagerError> {
+        let process_watcher = self.watcher.read().await;
+        if !process_watcher.is_running() {
+            return Err(WalletManagerError::WalletNotStarted);
+        }
+
+        process_watcher
+            .adapter
+            .cancel_transaction(tx_id, app_handle)
+            .await
+            .map_err(WalletManagerError::UnknownError)
+    }
+
+    /// Cancels an outgoing transaction that is not mined yet, releasing its inputs
+    pub async fn cancel_transaction(
+        &self,
+        tx_id: String,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<(), WalletManagerError> {
+        let transaction = self.get_latest_transaction(&tx_id).await?;
+        ensure_cancellable(&transaction)?;
+
+        {
+            let process_watcher = self.watcher.read().await;
+            process_watcher
+                .adapter
+                .cancel_transaction(tx_id.clone(), app_handle)
+                .await?;
+        }
+        info!(target: LOG_TARGET, "Cancelled transaction {tx_id}");
+        if let Err(e) = self.sync_transaction_history().await {
+            warn!(target: LOG_TARGET, "Failed to sync transaction history after cancelling {tx_id}: {e}");
+        }
+        Ok(())
+    }
+
+    /// Submits a signed outgoing transaction that is not mined yet straight to the node's mempool
+    /// again. The wallet already holds a record for the tx_id, so it is not broadcast through the
+    /// wallet a second time.
+    pub async fn rebroadcast_transaction(
+        &self,
+        tx_id: String,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<(), WalletManagerError> {
+        let transaction = self.get_latest_transaction(&tx_id).await?;
+        ensure_rebroadcastable(&transaction)?;
+
+        let signed_tx_json = self
+            .watcher
+            .read()
+            .await
+            .adapter
+            .read_signed_transaction(&tx_id, app_handle)?;
+        let signed_transaction = signed_transaction_for_node(&signed_tx_json)?;
+        self.node_manager
+            .submit_transaction(signed_transaction)
+            .await?;
+        info!(target: LOG_TARGET, "Rebroadcast transaction {tx_id}");
+        if let Err(e) = self.sync_transaction_history().await {
+            warn!(target: LOG_TARGET, "Failed to sync transaction history after rebroadcasting {tx_id}: {e}");
+        }
+        Ok(())
+    }
+
+    /// Syncs the history first so the safeguards check the current status
+    async fn get_latest_transaction(
+        &self,
+        tx_id: &str,
+    ) -> Result<TransactionInfo, WalletManagerError> {
+        if !self.watcher.read().await.is_running() {
+            return Err(WalletManagerError::WalletNotStarted);
+        }
+        self.sync_transaction_history().await?;
+        TransactionHistoryCache::current()
+            .read()
+            .await
+            .get(tx_id)
+            .cloned()
+            .ok_or_else(|| {
+                WalletManagerError::UnknownError(anyhow::anyhow!("Transaction {tx_id} not found"))
+            })
+    }
+
+    /// Sends a one-sided transaction to every recipient in order. Invalid recipients and failed
+    /// transactions are reported per recipient without stopping the rest of the batch. The PIN is
+    /// asked for once up front and reused for every transaction.
+    pub async fn send_batch_payment(
+        &self,
+        recipients: Vec<BatchPaymentRecipient>,
+        fee_per_gram: Option<u64>,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<BatchPaymentSummary, WalletManagerError> {
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_RECIPIENTS {
+            return Err(WalletManagerError::UnknownError(anyhow::anyhow!(
+                "A batch must have between 1 and {MAX_BATCH_RECIPIENTS} recipients"
+            )));
+        }
+        let process_watcher = self.watcher.read().await;
+        if !process_watcher.is_running() {
+            return Err(WalletManagerError::WalletNotStarted);
+        }
+
+        self.node_manager.wait_ready().await?;
+
+        let fee_per_gram = self.resolve_fee_per_gram(fee_per_gram).await?;
+        let fee = estimated_fee(fee_per_gram, 1);
+        let pin_password = PinManager::get_validated_pin_if_defined(app_handle).await?;
+        let mut summary = BatchPaymentSummary::default();
+
+        for recipient in recipients {
+            let amount = match recipient.validate() {
+                Ok(amount) => amount,
+                Err(error) => {
+                    summary.push(&recipient, BatchPaymentStatus::Invalid { error });
+                    continue;
+                }
+            };
+            // Re-read after every send, the change of a pending transaction is not spendable yet
+            let available_balance = process_watcher
+                .adapter
+                .get_balance()
+                .await?
+                .available_balance;
+            if amount + fee > available_balance {
+                summary.push(
+                    &recipient,
+                    BatchPaymentStatus::Skipped {
+                        error: "Insufficient balance".to_string(),
+                    },
+                );
+                continue;
+            }
+
+            let res = process_watcher
+                .adapter
+                .send_one_sided_to_stealth_address(
+                    amount.as_u64(),
+                    recipient.address.clone(),
+                    recipient.payment_id(),
+                    fee_per_gram,
+                    pin_password.clone(),
+                    app_handle,
+                )
+                .await;
+            match res {
+                Ok(()) => {
+                    summary.total_sent += amount;
+                    summary.push(&recipient, BatchPaymentStatus::Sent);
+                }
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Batch payment to {} failed: {e}", recipient.address);
+                    summary.push(
+                        &recipient,
+                        BatchPaymentStatus::Failed {
+                            error: e.to_string(),
+                        },
+                    );
+                }
+            }
+        }
+
+        info!(target: LOG_TARGET, "Batch payment finished: {} sent, {} failed", summary.sent_count, summary.failed_count);
+        Ok(summary)
+    }
+
+    /// Sends the available balance above `reserve` once it reaches `threshold`, returning the swept
+    /// amount. Immature coinbase outputs are time locked and never part of the available balance.
+    pub async fn sweep_available_balance(
+        &self,
+        destination: String,
+        threshold: MicroMinotari,
+        reserve: MicroMinotari,
+        payment_id: Option<String>,
+        app_handle: &tauri::AppHandle,
+    ) -> Result<Option<MicroMinotari>, WalletManagerError> {
+        let process_watcher = self.watcher.read().await;
+        if !process_watcher.is_running() {
+            return Err(WalletManagerError::WalletNotStarted);
+        }
+
+        self.node_manager.wait_ready().await?;
+
+        let available_balance = process_watcher
+            .adapter
+            .get_balance()
+            .await?
+            .available_balance;
+        if available_balance < threshold {
+            return Ok(None);
+        }
+        let fee_per_gram = self.resolve_fee_per_gram(None).await?;
+        // Sweeping the whole balance spends every unspent output, each of them adds to the fee
+        let input_count = process_watcher.adapter.get_unspent_output_count().await?;
+        let Some(amount) = sweep_amount(
+            available_balance,
+            threshold,
+            reserve,
+            estimated_fee(fee_per_gram, input_count),
+        ) else {
+            return Ok(None);
+        };
+
+        info!(target: LOG_TARGET, "Sweeping {amount} of {available_balance} available to {destination}");
+        let pin_password = PinManager::get_validated_pin_if_defined(app_handle).await?;
+        process_watcher
+            .adapter
+            .send_one_sided_to_stealth_address(
+                amount.as_u64(),
+                destination,
+                payment_id.filter(|id| !id.is_empty()),
+                fee_per_gram,
+                pin_password,
+                app_handle,
+            )
+            .await
+            .map_err(WalletManagerError::UnknownError)?;
+
+        Ok(Some(amount))
+    }
+
+    /// Falls back to the normal fee tier, or the minimum fee when estimation is unavailable
+    async fn resolve_fee_per_gram(
+        &self,
+        fee_per_gram: Option<u64>,
+    ) -> Result<u64, WalletManagerError> {
+        match fee_per_gram {
+            Some(fee_per_gram) if fee_per_gram < MIN_FEE_PER_GRAM => {
+                Err(WalletManagerError::UnknownError(anyhow::anyhow!(
+                    "Fee per gram must be at least {MIN_FEE_PER_GRAM}"
+                )))
+            }
+            Some(fee_per_gram) => Ok(fee_per_gram),
+            None => Ok(self
+                .estimate_transaction_fee()
+                .await
+                .map(|estimate| estimate.normal.fee_per_gram.as_u64())
+                .unwrap_or_else(|e| {
+                    warn!(target: LOG_TARGET, "Fee estimation failed, using the minimum fee: {e}");
+                    MIN_FEE_PER_GRAM
+                })),
+        }
+    }
+
+    /// Fee tiers for a one-sided transaction based on the node's mempool
+    pub async fn estimate_transaction_fee(&self) -> Result<FeeEstimate, WalletManagerError> {
+        let stats = self
+            .node_manager
+            .get_mempool_fee_per_gram_stats(FEE_STATS_BLOCK_COUNT)
+            .await?;
+        Ok(FeeEstimate::from_mempool_stats(&stats))
+    }
+
     pub async fn find_coinbase_transaction_for_block(
         &self,
         block_height: u64,
     ) -> Result<Option<TransactionInfo>, WalletManagerError> {
-        const COINBASE_STATUSES_BITFLAG: u32 = (1 << TransactionStatus::CoinbaseConfirmed as u32)
-            | (1 << TransactionStatus::CoinbaseUnconfirmed as u32);
-
         // Get a small batch of recent coinbase transactions
         let coinbase_txs = self
             .get_transactions(Some(0), Some(10), Some(COINBASE_STATUSES_BITFLAG))
@@ -311,6 +864,7 @@ impl WalletManager {
         // Start a background task to monitor the wallet state and emit scan progress updates
         TasksTrackers::current().wallet_phase.get_task_tracker().await.spawn(async move {
             let mut wallet_state_rx = wallet_state_receiver_clone;
+            let mut scan_rate_estimator = SyncRateEstimator::default();
             let mut shutdown_signal = TasksTrackers::current().wallet_phase.get_signal().await;
 
             loop {
@@ -339,10 +893,12 @@ impl WalletManager {
 
                         if scanned_height > 0 && progress < 100.0 {
                             log::info!(target: LOG_TARGET, "Initial wallet scanning: {progress}% ({scanned_height}/{current_target_height})");
+                            let scan_rate = scan_rate_estimator.update(scanned_height, current_target_height);
                             EventsEmitter::emit_init_wallet_scanning_progress(
                                 scanned_height,
                                 current_target_height,
                                 progress,
+                                scan_rate,
                             ).await;
                         }
                     }
@@ -413,6 +969,7 @@ impl WalletManager {
                                         current_target_height,
                                         current_target_height,
                                         100.0,
+                                        None,
                                     ).await;
 
                                     wallet_manager.initial_scan_completed
@@ -448,6 +1005,17 @@ impl WalletManager {
                     })
             });
 
+        let wallet_manager = self.clone();
+        TasksTrackers::current()
+            .wallet_phase
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                wallet_manager
+                    .sync_transaction_history_on_changes(wallet_state_receiver)
+                    .await;
+            });
+
         Ok(())
     }
 
@@ -484,6 +1052,51 @@ impl WalletManager {
         Ok(())
     }
 
+    /// Keeps the transaction history cache up to date while the wallet runs, syncing at most once
+    /// per interval after the scanned height or balance changed
+    async fn sync_transaction_history_on_changes(
+        &self,
+        mut wallet_state_receiver: watch::Receiver<Option<WalletState>>,
+    ) {
+        let mut interval = tokio::time::interval(TRANSACTION_HISTORY_SYNC_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut shutdown_signal = TasksTrackers::current().wallet_phase.get_signal().await;
+        let mut last_seen: Option<(u64, Option<WalletBalance>)> = None;
+        let mut sync_pending = true;
+
+        loop {
+            tokio::select! {
+                _ = shutdown_signal.wait() => {
+                    info!(target: LOG_TARGET, "Shutdown signal received, stopping transaction history sync");
+                    break;
+                }
+                result = wallet_state_receiver.changed() => {
+                    if result.is_err() {
+                        break;
+                    }
+                    let current = wallet_state_receiver
+                        .borrow()
+                        .as_ref()
+                        .map(|state| (state.scanned_height, state.balance.clone()));
+                    if current.is_some() && current != last_seen {
+                        last_seen = current;
+                        sync_pending = true;
+                    }
+                }
+                _ = interval.tick() => {
+                    if !sync_pending || !self.is_initial_scan_completed() {
+                        continue;
+                    }
+                    sync_pending = false;
+                    if let Err(e) = self.sync_transaction_history().await {
+                        warn!(target: LOG_TARGET, "Failed to sync transaction history: {e}");
+                        sync_pending = true;
+                    }
+                }
+            }
+        }
+    }
+
     #[allow(dead_code)]
     pub async fn stop(&self) -> Result<i32, WalletManagerError> {
         // Reset the initial scan flag
@@ -496,7 +1109,6 @@ impl WalletManager {
             .await
             .map_err(WalletManagerError::UnknownError)
     }
-    #[allow(dead_code)]
     pub async fn is_running(&self) -> bool {
         let process_watcher = self.watcher.read().await;
         process_watcher.is_running()
@@ -507,3 +1119,10 @@ impl WalletManager {
         lock.is_pid_file_exists(base_path)
     }
 }
+
+fn to_wallet_manager_error(e: WalletStatusMonitorError) -> WalletManagerError {
+    match e {
+        WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+        _ => WalletManagerError::UnknownError(e.into()),
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::events_emitter::EventsEmitter;
+use crate::setup::setup_manager::{SetupManager, SetupPhase};
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::wallet_rescan";
+
+/// Cipher seed birthdays count days from 2022-01-01
+const BIRTHDAY_GENESIS_FROM_UNIX_EPOCH: u64 = 1_640_995_200;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+// Block timestamps may lag behind, start the scan a day earlier to be safe
+const BIRTHDAY_SAFETY_MARGIN_DAYS: u16 = 1;
+const WALLET_RESTART_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+static RESCAN_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WalletRescanTarget {
+    Birthday { birthday: u16 },
+    BlockHeight { height: u64 },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "step")]
+pub enum WalletRescanStep {
+    Started { birthday: u16 },
+    Completed { scanned_height: u64 },
+    Failed { error: String },
+}
+
+pub fn birthday_from_timestamp(timestamp: u64) -> u16 {
+    let days = timestamp.saturating_sub(BIRTHDAY_GENESIS_FROM_UNIX_EPOCH) / SECONDS_PER_DAY;
+    u16::try_from(days)
+        .unwrap_or(u16::MAX)
+        .saturating_sub(BIRTHDAY_SAFETY_MARGIN_DAYS)
+}
+
+pub fn is_rescan_in_progress() -> bool {
+    RESCAN_IN_PROGRESS.load(Ordering::SeqCst)
+}
+
+/// Rebuilds the wallet database by scanning from `birthday`. Only the wallet data folder is
+/// removed, the app configuration and address book are kept.
+pub async fn run_wallet_rescan(app_handle: AppHandle, birthday: u16) {
+    if RESCAN_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        warn!(target: LOG_TARGET, "Wallet rescan already in progress");
+        return;
+    }
+    info!(target: LOG_TARGET, "Starting wallet rescan from birthday {birthday}");
+    EventsEmitter::emit_wallet_rescan_update(WalletRescanStep::Started { birthday }).await;
+
+    let step = match rescan(&app_handle, birthday).await {
+        Ok(scanned_height) => {
+            info!(target: LOG_TARGET, "Wallet rescan finished at height {scanned_height}");
+            WalletRescanStep::Completed { scanned_height }
+        }
+        Err(e) => {
+            error!(target: LOG_TARGET, "Wallet rescan failed: {e}");
+            WalletRescanStep::Failed {
+                error: e.to_string(),
+            }
+        }
+    };
+
+    RESCAN_IN_PROGRESS.store(false, Ordering::SeqCst);
+    EventsEmitter::emit_wallet_rescan_update(step).await;
+}
+
+async fn rescan(app_handle: &AppHandle, birthday: u16) -> Result<u64, anyhow::Error> {
+    let state = app_handle.state::<UniverseAppState>();
+    let base_path = app_handle.path().app_local_data_dir()?;
+
+    SetupManager::get_instance()
+        .shutdown_phases(vec![SetupPhase::Wallet])
+        .await;
+    let cleaned = state.wallet_manager.clean_data_folder(&base_path).await;
+    if cleaned.is_ok() {
+        state.wallet_manager.set_pending_birthday(birthday).await;
+    }
+    SetupManager::get_instance()
+        .resume_phases(vec![SetupPhase::Wallet])
+        .await;
+    cleaned?;
+
+    // Scan progress is reported by the wallet phase while it waits for the initial scan
+    let started = tokio::time::timeout(WALLET_RESTART_TIMEOUT, async {
+        while !state.wallet_manager.is_running().await {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+    })
+    .await;
+    if started.is_err() {
+        return Err(anyhow::anyhow!(
+            "Wallet did not start after clearing its data"
+        ));
+    }
+
+    let tip_height = state.node_status_watch_rx.borrow().block_height;
+    let wallet_state = state
+        .wallet_manager
+        .wait_for_scan_to_height(tip_height, None)
+        .await?;
+    Ok(wallet_state.scanned_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn birthday_counts_days_with_safety_margin() {
+        let ten_days_in = BIRTHDAY_GENESIS_FROM_UNIX_EPOCH + 10 * SECONDS_PER_DAY + 3600;
+        assert_eq!(birthday_from_timestamp(ten_days_in), 9);
+    }
+
+    #[test]
+    fn birthday_saturates_outside_range() {
+        assert_eq!(birthday_from_timestamp(0), 0);
+        assert_eq!(birthday_from_timestamp(u64::MAX), u16::MAX - 1);
+    }
+}
@@ -22,6 +22,7 @@
 
 use minotari_node_grpc_client::grpc::{GetBalanceResponse, NetworkStatusResponse};
 use serde::{Serialize, Serializer};
+use tari_common_types::tari_address::TariAddress;
 use tari_transaction_components::tari_amount::MicroMinotari;
 
 #[allow(dead_code)]
@@ -73,7 +74,7 @@ pub enum ConnectivityStatus {
     Offline,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct WalletBalance {
     pub available_balance: MicroMinotari,
     pub timelocked_balance: MicroMinotari,
@@ -96,7 +97,7 @@ impl WalletBalance {
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
 pub struct TransactionInfo {
     pub tx_id: String,
     pub source_address: String,
@@ -120,6 +121,16 @@ pub struct TariAddressVariants {
     pub hex: String,
 }
 
+impl From<&TariAddress> for TariAddressVariants {
+    fn from(address: &TariAddress) -> Self {
+        Self {
+            emoji_string: address.to_emoji_string(),
+            base58: address.to_base58(),
+            hex: address.to_hex(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i32)]
 pub enum TransactionStatus {
@@ -155,6 +166,28 @@ pub enum TransactionStatus {
     CoinbaseNotInBlockChain = 14,
 }
 
+impl TransactionStatus {
+    /// Mined and buried deep enough that the wallet no longer expects it to be reorged out
+    pub fn is_confirmed(&self) -> bool {
+        matches!(
+            self,
+            TransactionStatus::MinedConfirmed
+                | TransactionStatus::OneSidedConfirmed
+                | TransactionStatus::CoinbaseConfirmed
+        )
+    }
+
+    pub fn is_coinbase(&self) -> bool {
+        matches!(
+            self,
+            TransactionStatus::Coinbase
+                | TransactionStatus::CoinbaseUnconfirmed
+                | TransactionStatus::CoinbaseConfirmed
+                | TransactionStatus::CoinbaseNotInBlockChain
+        )
+    }
+}
+
 // We should decide which format we wanna use
 impl Serialize for TransactionStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
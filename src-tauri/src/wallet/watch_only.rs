@@ -0,0 +1,229 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tari_common::configuration::Network;
+use tari_common_types::tari_address::TariAddress;
+use tari_common_types::types::{CompressedPublicKey, PrivateKey};
+use tari_utilities::hex::Hex;
+use tari_utilities::SafePassword;
+
+use crate::configs::config_wallet::WalletId;
+use crate::internal_wallet::TariWalletDetails;
+use crate::utils::cryptography;
+
+const WATCH_ONLY_PACKAGE_VERSION: u8 = 1;
+pub const WATCH_ONLY_WALLET_ID: &str = "watch_only";
+
+/// Everything the read only wallet needs to scan for the outputs of an address, without the seed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WatchOnlyPackage {
+    pub version: u8,
+    /// Base58
+    pub tari_address: String,
+    pub view_private_key_hex: String,
+    pub spend_public_key_hex: String,
+    pub wallet_birthday: u16,
+}
+
+impl WatchOnlyPackage {
+    pub fn from_wallet_details(details: &TariWalletDetails) -> Self {
+        Self {
+            version: WATCH_ONLY_PACKAGE_VERSION,
+            tari_address: details.tari_address.to_base58(),
+            view_private_key_hex: details.view_private_key_hex.clone(),
+            spend_public_key_hex: details.spend_public_key_hex.clone(),
+            wallet_birthday: details.wallet_birthday,
+        }
+    }
+
+    pub fn to_wallet_details(&self) -> Result<TariWalletDetails, anyhow::Error> {
+        Ok(TariWalletDetails {
+            id: WalletId::new(WATCH_ONLY_WALLET_ID.to_string()),
+            tari_address: self.parse_tari_address()?,
+            wallet_birthday: self.wallet_birthday,
+            view_private_key_hex: self.view_private_key_hex.clone(),
+            spend_public_key_hex: self.spend_public_key_hex.clone(),
+        })
+    }
+
+    /// Every watched address gets its own data dir so switching packages never reuses another address' database
+    pub fn data_dir_name(&self) -> String {
+        let address_hash = Sha256::digest(self.tari_address.as_bytes());
+        format!(
+            "{WATCH_ONLY_WALLET_ID}_{}",
+            &address_hash.to_vec().to_hex()[..16]
+        )
+    }
+
+    fn parse_tari_address(&self) -> Result<TariAddress, anyhow::Error> {
+        TariAddress::from_base58(&self.tari_address)
+            .map_err(|_| anyhow!("Watch-only package has an invalid address"))
+    }
+
+    /// Checks the package targets the current network and that its keys belong to its address
+    pub fn validate(&self, network: Network) -> Result<(), anyhow::Error> {
+        if self.version > WATCH_ONLY_PACKAGE_VERSION {
+            return Err(anyhow!(
+                "Watch-only package version {} is not supported",
+                self.version
+            ));
+        }
+        let tari_address = self.parse_tari_address()?;
+        if tari_address.network() != network {
+            return Err(anyhow!(
+                "Watch-only package is for {} but the app runs on {network}",
+                tari_address.network()
+            ));
+        }
+
+        let view_private_key = PrivateKey::from_hex(&self.view_private_key_hex)
+            .map_err(|_| anyhow!("Watch-only package has an invalid view key"))?;
+        let spend_public_key = CompressedPublicKey::from_hex(&self.spend_public_key_hex)
+            .map_err(|_| anyhow!("Watch-only package has an invalid spend public key"))?;
+        let derived_address = TariAddress::new_dual_address(
+            CompressedPublicKey::from_secret_key(&view_private_key),
+            spend_public_key,
+            network,
+            tari_address.features(),
+            None,
+        )
+        .map_err(|e| anyhow!(e.to_string()))?;
+        if derived_address != tari_address {
+            return Err(anyhow!("Watch-only package keys don't match its address"));
+        }
+        Ok(())
+    }
+}
+
+/// The package holds the private view key, so it is only ever written encrypted with `passphrase`
+pub fn write_watch_only_package(
+    package: &WatchOnlyPackage,
+    path: &Path,
+    passphrase: &SafePassword,
+) -> Result<(), anyhow::Error> {
+    fs::write(path, encrypt_watch_only_package(package, passphrase)?)?;
+    Ok(())
+}
+
+pub fn read_watch_only_package(
+    path: &Path,
+    passphrase: &SafePassword,
+) -> Result<WatchOnlyPackage, anyhow::Error> {
+    let package = decrypt_watch_only_package(&fs::read(path)?, passphrase)?;
+    package.validate(Network::get_current())?;
+    Ok(package)
+}
+
+fn encrypt_watch_only_package(
+    package: &WatchOnlyPackage,
+    passphrase: &SafePassword,
+) -> Result<Vec<u8>, anyhow::Error> {
+    cryptography::encrypt(serde_json::to_string(package)?.as_bytes(), passphrase)
+}
+
+fn decrypt_watch_only_package(
+    encrypted_package: &[u8],
+    passphrase: &SafePassword,
+) -> Result<WatchOnlyPackage, anyhow::Error> {
+    let package_json = cryptography::decrypt(encrypted_package, passphrase)
+        .map_err(|_| anyhow!("Wrong passphrase or not a watch-only package"))?;
+    serde_json::from_slice(&package_json).map_err(|e| anyhow!("Not a watch-only package: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use rand::thread_rng;
+    use tari_common_types::tari_address::TariAddressFeatures;
+    use tari_crypto::keys::SecretKey;
+
+    fn package(network: Network) -> WatchOnlyPackage {
+        let view_private_key = PrivateKey::random(&mut thread_rng());
+        let spend_public_key =
+            CompressedPublicKey::from_secret_key(&PrivateKey::random(&mut thread_rng()));
+        let tari_address = TariAddress::new_dual_address(
+            CompressedPublicKey::from_secret_key(&view_private_key),
+            spend_public_key.clone(),
+            network,
+            TariAddressFeatures::create_one_sided_only(),
+            None,
+        )
+        .unwrap();
+
+        WatchOnlyPackage {
+            version: WATCH_ONLY_PACKAGE_VERSION,
+            tari_address: tari_address.to_base58(),
+            view_private_key_hex: view_private_key.to_hex(),
+            spend_public_key_hex: spend_public_key.to_hex(),
+            wallet_birthday: 100,
+        }
+    }
+
+    #[test]
+    fn accepts_consistent_package() {
+        let package = package(Network::Esmeralda);
+        assert!(package.validate(Network::Esmeralda).is_ok());
+        assert_eq!(package.to_wallet_details().unwrap().wallet_birthday, 100);
+    }
+
+    #[test]
+    fn rejects_other_network() {
+        let package = package(Network::Esmeralda);
+        assert!(package.validate(Network::MainNet).is_err());
+    }
+
+    #[test]
+    fn rejects_keys_of_another_address() {
+        let mut package = package(Network::Esmeralda);
+        package.view_private_key_hex = PrivateKey::random(&mut thread_rng()).to_hex();
+        assert!(package.validate(Network::Esmeralda).is_err());
+    }
+
+    #[test]
+    fn encrypted_package_needs_the_passphrase() {
+        let package = package(Network::Esmeralda);
+        let passphrase = SafePassword::from("correct horse battery staple");
+        let encrypted = encrypt_watch_only_package(&package, &passphrase).unwrap();
+
+        assert!(!String::from_utf8_lossy(&encrypted).contains(&package.view_private_key_hex));
+        assert_eq!(
+            decrypt_watch_only_package(&encrypted, &passphrase).unwrap(),
+            package
+        );
+        assert!(decrypt_watch_only_package(&encrypted, &SafePassword::from("wrong")).is_err());
+    }
+
+    #[test]
+    fn data_dir_is_keyed_by_address() {
+        let first = package(Network::Esmeralda);
+        let second = package(Network::Esmeralda);
+        assert_eq!(first.data_dir_name(), first.clone().data_dir_name());
+        assert_ne!(first.data_dir_name(), second.data_dir_name());
+    }
+}
@@ -49,6 +49,163 @@ export interface TransactionInfo {
     payment_reference?: string;
 }
 
+export interface FeeTierEstimate {
+    fee_per_gram: number;
+    estimated_fee: number;
+    target_blocks: number;
+}
+
+export interface FeeEstimate {
+    slow: FeeTierEstimate;
+    normal: FeeTierEstimate;
+    fast: FeeTierEstimate;
+}
+
+export interface BatchPaymentRecipient {
+    address: string;
+    amount: string;
+    payment_id?: string;
+}
+
+export type BatchPaymentResult = {
+    index: number;
+    address: string;
+    amount: string;
+} & (
+    | { status: 'sent' }
+    | { status: 'invalid'; error: string }
+    | { status: 'failed'; error: string }
+    | { status: 'skipped'; error: string }
+);
+
+export interface BatchPaymentSummary {
+    results: BatchPaymentResult[];
+    sent_count: number;
+    failed_count: number;
+    total_sent: number;
+}
+
+export interface AutoSweepRule {
+    id: string;
+    enabled: boolean;
+    destination: string;
+    threshold: number;
+    reserve: number;
+    min_interval_secs: number;
+    payment_id?: string;
+    last_attempt_at?: number;
+}
+
+export type AutoSweepLogEntry = {
+    rule_id: string;
+    timestamp: number;
+    destination: string;
+    amount: number;
+} & ({ status: 'sent' } | { status: 'skipped'; reason: string } | { status: 'failed'; error: string });
+
+export interface CoinbaseOutputMaturity {
+    tx_id: string;
+    amount: number;
+    mined_height: number;
+    maturity_height: number;
+    blocks_remaining: number;
+    estimated_unlock_timestamp: number;
+    is_mature: boolean;
+}
+
+export interface CoinbaseRewardsMaturedPayload {
+    block_height: number;
+    count: number;
+    amount: number;
+}
+
+export type WalletRescanTarget = { type: 'birthday'; birthday: number } | { type: 'block_height'; height: number };
+
+export type WalletRescanStep =
+    | { step: 'Started'; birthday: number }
+    | { step: 'Completed'; scanned_height: number }
+    | { step: 'Failed'; error: string };
+
+export interface WatchOnlyPackage {
+    version: number;
+    tari_address: string;
+    view_private_key_hex: string;
+    spend_public_key_hex: string;
+    wallet_birthday: number;
+}
+
+export type PaymentRequestStatus = 'Pending' | 'PartiallyPaid' | 'PendingConfirmation' | 'Paid' | 'Expired';
+
+export interface PaymentRequest {
+    payment_id: string;
+    amount: number;
+    memo: string;
+    tari_address: string;
+    request_address: string;
+    created_at: number;
+    expires_at?: number;
+    status: PaymentRequestStatus;
+    received: number;
+    pending: number;
+    tx_ids: string[];
+}
+
+export interface InternalWalletInfo {
+    id: string;
+    name: string;
+    tari_address?: string;
+    is_selected: boolean;
+}
+
+export interface PendingOfflineTransaction {
+    tx_id: string;
+    created_at: number;
+}
+
+export interface ContactInput {
+    name: string;
+    address: string;
+    labels?: string[];
+    notes?: string;
+    default_payment_id?: string;
+}
+
+export interface Contact {
+    name: string;
+    address: TariAddressVariants;
+    labels: string[];
+    notes: string;
+    default_payment_id?: string;
+    last_used_at?: number;
+}
+
+export interface ContactImportSummary {
+    imported: number;
+    duplicates: string[];
+    invalid: string[];
+}
+
+export interface TransactionHistoryFilter {
+    status_bitflag?: number;
+    direction?: number;
+    from_timestamp?: number;
+    to_timestamp?: number;
+    min_amount?: number;
+    max_amount?: number;
+}
+
+export interface TransactionSearchQuery extends TransactionHistoryFilter {
+    memo?: string;
+    payment_id?: string;
+    address?: string;
+}
+
+export interface TransactionHistoryUpdatePayload {
+    added: TransactionInfo[];
+    updated: TransactionInfo[];
+    removed: string[];
+}
+
 export interface P2poolStatsResult {
     connection_info: P2poolConnectionInfo;
     connected_since?: number;
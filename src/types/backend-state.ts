@@ -5,22 +5,30 @@ import {
     CriticalProblemPayload,
     DetectedAvailableGpuEngines,
     DetectedDevicesPayload,
+    MinorityChainMiningStoppedPayload,
     NewBlockHeightPayload,
     NodeTypeUpdatePayload,
     ProgressTrackerUpdatePayload,
     SetupPhase,
     ShowReleaseNotesPayload,
+    SnapshotBootstrapResultPayload,
+    SyncRate,
     TariAddressUpdatePayload,
     WalletUIMode,
 } from './events-payloads.ts';
 import {
+    AutoSweepLogEntry,
     BaseNodeStatus,
+    CoinbaseRewardsMaturedPayload,
     CpuMinerStatus,
     GpuMinerStatus,
     NetworkStatus,
+    PaymentRequest,
     PoolStats,
     SystemDependency,
+    TransactionHistoryUpdatePayload,
     WalletBalance,
+    WalletRescanStep,
 } from './app-status.ts';
 import { ConfigCore, ConfigMining, ConfigUI, ConfigWallet, GpuDeviceSettings } from './configs.ts';
 import { DisabledPhasesPayload } from '@app/store/actions/setupStoreActions.ts';
@@ -48,6 +56,26 @@ export type BackendStateUpdateEvent =
           event_type: 'WalletBalanceUpdate';
           payload: WalletBalance;
       }
+    | {
+          event_type: 'TransactionHistoryUpdate';
+          payload: TransactionHistoryUpdatePayload;
+      }
+    | {
+          event_type: 'AutoSweepUpdate';
+          payload: AutoSweepLogEntry;
+      }
+    | {
+          event_type: 'CoinbaseRewardsMatured';
+          payload: CoinbaseRewardsMaturedPayload;
+      }
+    | {
+          event_type: 'WalletRescanUpdate';
+          payload: WalletRescanStep;
+      }
+    | {
+          event_type: 'PaymentRequestUpdate';
+          payload: PaymentRequest;
+      }
     | {
           event_type: 'CpuMiningUpdate';
           payload: CpuMinerStatus;
@@ -84,6 +112,14 @@ export type BackendStateUpdateEvent =
           event_type: 'StuckOnOrphanChain';
           payload: boolean;
       }
+    | {
+          event_type: 'MinorityChainMiningStopped';
+          payload: MinorityChainMiningStoppedPayload;
+      }
+    | {
+          event_type: 'NodeSnapshotBootstrapResult';
+          payload: SnapshotBootstrapResultPayload;
+      }
     | {
           event_type: 'ShowReleaseNotes';
           payload: ShowReleaseNotesPayload;
@@ -138,6 +174,7 @@ export type BackendStateUpdateEvent =
               scanned_height: number;
               total_height: number;
               progress: number;
+              scan_rate?: SyncRate;
           };
       }
     | {
@@ -1,5 +1,6 @@
 import { NodeType } from '@app/store/useNodeStore';
 import { WalletUIMode } from './events-payloads';
+import { AutoSweepLogEntry, AutoSweepRule, WatchOnlyPackage } from './app-status';
 
 export interface ConfigCore {
     created_at: string;
@@ -31,6 +32,10 @@ export interface ConfigWallet {
     wxtm_addresses: Record<string, string>; // Ethereum addresses used for WXTm mode
     keyring_accessed: boolean;
     last_known_balance?: number;
+    auto_sweep_rules?: AutoSweepRule[];
+    auto_sweep_log?: AutoSweepLogEntry[];
+    signer_only_mode?: boolean;
+    watch_only_package?: WatchOnlyPackage;
 }
 export interface ConfigUI {
     created_at: string;
@@ -47,6 +47,18 @@ export interface CriticalProblemPayload {
     error_message?: string;
 }
 
+export interface MinorityChainMiningStoppedPayload {
+    cpu_mining_stopped: boolean;
+    gpu_mining_stopped: boolean;
+}
+
+export interface SnapshotBootstrapResultPayload {
+    success: boolean;
+    verified: boolean;
+    height?: number;
+    error?: string;
+}
+
 export interface ShowReleaseNotesPayload {
     release_notes: string;
     is_app_update_available: boolean;
@@ -62,6 +74,11 @@ export interface NodeTypeUpdatePayload {
     node_connection_address?: string;
 }
 
+export interface SyncRate {
+    items_per_second: number;
+    eta_seconds?: number;
+}
+
 export type BackgroundNodeSyncUpdatePayload =
     | {
           step: 'Startup';
@@ -74,6 +91,7 @@ export type BackgroundNodeSyncUpdatePayload =
           tip_header_height: number;
           local_block_height: number;
           tip_block_height: number;
+          headers_rate?: SyncRate;
       }
     | {
           step: 'Block';
@@ -81,6 +99,7 @@ export type BackgroundNodeSyncUpdatePayload =
           tip_header_height: number;
           local_block_height: number;
           tip_block_height: number;
+          blocks_rate?: SyncRate;
       }
     | {
           step: 'Done';
@@ -1,11 +1,26 @@
 import {
     ApplicationsVersions,
+    AutoSweepLogEntry,
+    AutoSweepRule,
     P2poolStatsResult,
     TorConfig,
     TransactionInfo,
+    TransactionHistoryFilter,
+    TransactionSearchQuery,
+    FeeEstimate,
+    BatchPaymentRecipient,
+    BatchPaymentSummary,
+    CoinbaseOutputMaturity,
+    Contact,
+    ContactImportSummary,
+    ContactInput,
+    PendingOfflineTransaction,
+    InternalWalletInfo,
+    PaymentRequest,
     P2poolConnections,
     BridgeEnvs,
     TariAddressVariants,
+    WalletRescanTarget,
     BaseNodeStatus,
 } from './app-status';
 import { Language } from '@app/i18initializer';
@@ -19,8 +34,52 @@ import { ActiveTapplet } from '@app/types/tapplets/tapplet.types';
 declare module '@tauri-apps/api/core' {
     function invoke(
         param: 'send_one_sided_to_stealth_address',
-        payload: { amount: string; destination: string; paymentId?: string }
+        payload: { amount: string; destination: string; paymentId?: string; feePerGram?: number }
     ): Promise<void>;
+    function invoke(param: 'estimate_transaction_fee'): Promise<FeeEstimate>;
+    function invoke(
+        param: 'send_batch_payment',
+        payload: { recipients: BatchPaymentRecipient[]; feePerGram?: number }
+    ): Promise<BatchPaymentSummary>;
+    function invoke(
+        param: 'prepare_offline_transaction',
+        payload: { amount: string; destination: string; paymentId?: string; feePerGram?: number; path: string }
+    ): Promise<string>;
+    function invoke(
+        param: 'sign_offline_transaction',
+        payload: { inputPath: string; outputPath: string }
+    ): Promise<string>;
+    function invoke(param: 'broadcast_offline_signed_transaction', payload: { path: string }): Promise<string>;
+    function invoke(param: 'cancel_offline_transaction', payload: { txId: string }): Promise<void>;
+    function invoke(param: 'cancel_transaction', payload: { txId: string }): Promise<void>;
+    function invoke(param: 'rebroadcast_transaction', payload: { txId: string }): Promise<void>;
+    function invoke(param: 'get_pending_offline_transactions'): Promise<PendingOfflineTransaction[]>;
+    function invoke(param: 'set_signer_only_mode', payload: { enabled: boolean }): Promise<void>;
+    function invoke(param: 'export_watch_only_package', payload: { path: string; passphrase: string }): Promise<void>;
+    function invoke(param: 'enable_watch_only_mode', payload: { path: string; passphrase: string }): Promise<string>;
+    function invoke(param: 'disable_watch_only_mode'): Promise<void>;
+    function invoke(
+        param: 'create_payment_request',
+        payload: { amount: string; memo?: string; expiresInSecs?: number }
+    ): Promise<PaymentRequest>;
+    function invoke(param: 'get_payment_requests'): Promise<PaymentRequest[]>;
+    function invoke(param: 'delete_payment_request', payload: { paymentId: string }): Promise<void>;
+    function invoke(param: 'list_internal_wallets'): Promise<InternalWalletInfo[]>;
+    function invoke(param: 'create_internal_wallet', payload: { name: string }): Promise<string>;
+    function invoke(param: 'rename_internal_wallet', payload: { walletId: string; name: string }): Promise<void>;
+    function invoke(param: 'delete_internal_wallet', payload: { walletId: string; confirmed: boolean }): Promise<void>;
+    function invoke(param: 'switch_internal_wallet', payload: { walletId: string }): Promise<void>;
+    function invoke(param: 'get_auto_sweep_rules'): Promise<AutoSweepRule[]>;
+    function invoke(param: 'set_auto_sweep_rules', payload: { rules: AutoSweepRule[] }): Promise<void>;
+    function invoke(param: 'get_auto_sweep_log'): Promise<AutoSweepLogEntry[]>;
+    function invoke(param: 'get_contacts'): Promise<Contact[]>;
+    function invoke(param: 'save_contact', payload: { contact: ContactInput; previousName?: string }): Promise<Contact>;
+    function invoke(param: 'remove_contact', payload: { name: string }): Promise<void>;
+    function invoke(param: 'export_contacts', payload: { path: string; format: 'csv' | 'json' }): Promise<number>;
+    function invoke(
+        param: 'import_contacts',
+        payload: { path: string; format: 'csv' | 'json' }
+    ): Promise<ContactImportSummary>;
     function invoke(
         param: 'set_should_always_use_system_language',
         payload: { shouldAlwaysUseSystemLanguage: boolean }
@@ -69,6 +128,26 @@ declare module '@tauri-apps/api/core' {
         param: 'get_transactions',
         payload: { offset?: number; limit?: number; statusBitflag?: number }
     ): Promise<TransactionInfo[]>;
+    function invoke(
+        param: 'get_transaction_history',
+        payload: { filter?: TransactionHistoryFilter; offset?: number; limit?: number }
+    ): Promise<TransactionInfo[]>;
+    function invoke(
+        param: 'search_transactions',
+        payload: { query: TransactionSearchQuery; offset?: number; limit?: number }
+    ): Promise<TransactionInfo[]>;
+    function invoke(
+        param: 'export_transaction_history',
+        payload: {
+            path: string;
+            format: 'csv' | 'json';
+            fromTimestamp?: number;
+            toTimestamp?: number;
+            useLocalTimeZone?: boolean;
+            utcOffsetMinutes?: number;
+        }
+    ): Promise<number>;
+    function invoke(param: 'get_coinbase_maturity_schedule'): Promise<CoinbaseOutputMaturity[]>;
     function invoke(param: 'import_seed_words', payload: { seedWords: string[] }): Promise<void>;
     function invoke(param: 'get_tor_config'): Promise<TorConfig>;
     function invoke(param: 'set_tor_config', payload: { config: TorConfig }): Promise<TorConfig>;
@@ -127,6 +206,7 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'get_bridge_envs'): Promise<BridgeEnvs>;
     function invoke(param: 'parse_tari_address', payload: { address: string }): Promise<TariAddressVariants>;
     function invoke(param: 'refresh_wallet_history'): Promise<void>;
+    function invoke(param: 'rescan_wallet', payload: { target: WalletRescanTarget }): Promise<number>;
     function invoke(param: 'get_base_node_status'): Promise<BaseNodeStatus>;
     function invoke(param: 'create_pin'): Promise<void>;
     function invoke(param: 'forgot_pin', payload: { seedWords: string[] }): Promise<void>;
@@ -151,5 +231,6 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'reset_cpu_pool_config', payload: { cpuPoolName: string }): Promise<void>;
     function invoke(param: 'restart_phases', payload: { phases: SetupPhase[] }): Promise<void>;
     function invoke(param: 'list_connected_peers'): Promise<string[]>;
+    function invoke(param: 'set_chain_tip_verification_nodes', payload: { nodes: string[] }): Promise<void>;
     function invoke(param: 'set_feedback_fields', payload: { feedbackType: string; wasSent: boolean }): Promise<void>;
 }